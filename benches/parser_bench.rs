@@ -0,0 +1,106 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rust_rsshub::parser::{CompiledParser, HtmlParser};
+use rust_rsshub::{ListParserConfig, ParserConfig};
+use scraper::{ElementRef, Html, Selector};
+
+/// 生成包含 `count` 篇文章的列表页
+fn sample_html(count: usize) -> String {
+    let mut html = String::from("<html><body>");
+    for i in 0..count {
+        html.push_str(&format!(
+            r#"<div class="post">
+                <h2 class="title">Article {i}</h2>
+                <a class="link" href="/article/{i}">Read more</a>
+                <p class="desc">Description for article {i}</p>
+                <time class="date">2024-01-15</time>
+                <span class="author">Author {i}</span>
+                <span class="category">Tech</span>
+            </div>"#
+        ));
+    }
+    html.push_str("</body></html>");
+    html
+}
+
+fn sample_config() -> ParserConfig {
+    ParserConfig {
+        list: ListParserConfig {
+            selector: "div.post".to_string(),
+            link_selector: vec!["a.featured-link".to_string(), "a.link".to_string()].into(),
+            title_selector: vec!["h1.featured-title".to_string(), "h2.title".to_string()].into(),
+            description_selector: Some("p.desc".into()),
+            date_selector: Some("time.date".into()),
+            date_format: Some("%Y-%m-%d".to_string()),
+            author_selector: Some("span.author".into()),
+            category_selector: Some("span.category".into()),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+/// 旧实现的做法：每篇文章的每个字段都调用一次 `Selector::parse`
+fn parse_with_per_article_selectors(html: &str, config: &ParserConfig) -> usize {
+    fn text(element: &ElementRef, selector: &str) -> String {
+        let sel = Selector::parse(selector).unwrap();
+        element
+            .select(&sel)
+            .next()
+            .map(|el| el.text().collect::<String>().trim().to_string())
+            .unwrap_or_default()
+    }
+
+    let document = Html::parse_document(html);
+    let list = Selector::parse(&config.list.selector).unwrap();
+    let optional = [
+        &config.list.description_selector,
+        &config.list.date_selector,
+        &config.list.author_selector,
+        &config.list.category_selector,
+    ];
+
+    let mut count = 0;
+    for element in document.select(&list) {
+        let fields = config.list.title_selector.selectors.iter()
+            .chain(config.list.link_selector.selectors.iter())
+            .chain(optional.iter().filter_map(|f| f.as_ref()).flat_map(|f| f.selectors.iter()));
+        for selector in fields {
+            black_box(text(&element, selector));
+        }
+        count += 1;
+    }
+    count
+}
+
+fn bench_parse(c: &mut Criterion) {
+    let html = sample_html(50);
+    let config = sample_config();
+    let compiled = CompiledParser::compile(&config).unwrap();
+
+    let mut group = c.benchmark_group("parse_50_articles");
+
+    // 旧实现：每篇文章的每个字段都编译选择器
+    group.bench_function("compile_per_article", |b| {
+        b.iter(|| parse_with_per_article_selectors(black_box(&html), &config))
+    });
+
+    // 每次请求编译一次选择器
+    group.bench_function("compile_per_request", |b| {
+        b.iter(|| HtmlParser::parse(black_box(&html), &config, "https://example.com", 50).unwrap())
+    });
+
+    // 使用插件加载时缓存的解析器
+    group.bench_function("precompiled", |b| {
+        b.iter(|| compiled.parse(black_box(&html), "https://example.com", 50).unwrap())
+    });
+
+    group.finish();
+
+    // 单独衡量编译的开销
+    c.bench_function("compile_parser", |b| {
+        b.iter(|| CompiledParser::compile(black_box(&config)).unwrap())
+    });
+}
+
+criterion_group!(benches, bench_parse);
+criterion_main!(benches);
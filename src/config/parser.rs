@@ -1,6 +1,10 @@
 use crate::error::Result;
-use crate::config::types::{PluginConfig, SourceConfig};
+use crate::config::types::{FieldSelector, GuidConfig, ListParserConfig, ParserMode, PluginConfig, SourceConfig};
 use crate::error::RssHubError;
+use crate::parser::compiled::compile_lists;
+use crate::parser::CompiledParser;
+use crate::plugins::filter::CompiledFilter;
+use crate::plugins::template::FeedTemplates;
 use std::path::Path;
 use std::fs;
 use std::time::SystemTime;
@@ -70,6 +74,107 @@ impl ConfigParser {
             return Err(RssHubError::InvalidUrl(url.clone()));
         }
 
+        // 只有 CSS 模式使用 list 中的选择器（包括 item_selector），其他模式下不会生效，直接报错
+        if config.parser.mode == ParserMode::Css {
+            Self::validate_lists(config)?;
+        } else if !config.parser.list.selector.is_empty() || !config.parser.lists.is_empty() {
+            return Err(RssHubError::Config(
+                "parser.list and parser.lists are only supported in css mode".into(),
+            ));
+        }
+
+        // GUID 选择器
+        if let Some(GuidConfig::Selector { ref selector, .. }) = config.parser.guid {
+            Self::validate_field_selector("guid", &FieldSelector::from(selector.clone()))?;
+        }
+
+        // 编译选择器和正则，无效的规则在加载时就报错
+        CompiledParser::for_plugin(config)?;
+        if let Some(ref filter) = config.filter {
+            CompiledFilter::compile(filter)?;
+        }
+        FeedTemplates::compile(config)?;
+
+        Ok(())
+    }
+
+    /// 验证 CSS 模式的列表页解析规则，`lists` 中的每个规则单独验证
+    fn validate_lists(config: &PluginConfig) -> Result<()> {
+        if config.parser.lists.is_empty() && config.parser.list.selector.trim().is_empty() {
+            return Err(RssHubError::Config("parser.list or parser.lists is required in css mode".into()));
+        }
+
+        compile_lists(&config.parser, |list| Self::validate_list(config, list))?;
+        Ok(())
+    }
+
+    fn validate_list(config: &PluginConfig, list: &ListParserConfig) -> Result<()> {
+        // 验证字段选择器
+        let fields = [
+            ("title_selector", Some(&list.title_selector)),
+            ("link_selector", Some(&list.link_selector)),
+            ("description_selector", list.description_selector.as_ref()),
+            ("date_selector", list.date_selector.as_ref()),
+            ("author_selector", list.author_selector.as_ref()),
+            ("category_selector", list.category_selector.as_ref()),
+            ("enclosure_selector", list.enclosure_selector.as_ref()),
+            ("image_selector", list.image_selector.as_ref()),
+        ];
+        for (name, field) in fields {
+            if let Some(field) = field {
+                Self::validate_field_selector(name, field)?;
+
+                // 目前只有描述支持保留 HTML
+                if field.html && name != "description_selector" {
+                    return Err(RssHubError::Config(format!(
+                        "{} does not support html mode",
+                        name
+                    )));
+                }
+            }
+        }
+
+        // 自定义字段只提取文本
+        for (name, field) in &list.extra_fields {
+            let name = format!("extra_fields.{}", name);
+            Self::validate_field_selector(&name, field)?;
+            if field.html {
+                return Err(RssHubError::Config(format!("{} does not support html mode", name)));
+            }
+        }
+
+        // item_selector 需要在 HTML 文档中按容器划分作用域，JSON 数据无法支持
+        if let Some(ref item_selector) = list.item_selector {
+            if item_selector.trim().is_empty() {
+                return Err(RssHubError::Config("item_selector cannot be empty".into()));
+            }
+            match config.source {
+                SourceConfig::Json(_) => {
+                    return Err(RssHubError::Config(
+                        "item_selector is not supported for json sources".into(),
+                    ));
+                }
+                SourceConfig::Html(ref html) if html.embedded.is_some() => {
+                    return Err(RssHubError::Config(
+                        "item_selector is not supported for embedded data".into(),
+                    ));
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// 验证字段选择器至少包含一个非空选择器
+    fn validate_field_selector(name: &str, field: &FieldSelector) -> Result<()> {
+        if field.selectors.is_empty() || field.selectors.iter().any(|s| s.trim().is_empty()) {
+            return Err(RssHubError::Config(format!(
+                "{} must contain at least one non-empty selector",
+                name
+            )));
+        }
+
         Ok(())
     }
 
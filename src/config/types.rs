@@ -1,5 +1,5 @@
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 /// 插件配置根节点
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -10,6 +10,18 @@ pub struct PluginConfig {
     #[serde(default)]
     pub cache: CacheConfig,
     pub feed: FeedConfig,
+    /// 条目历史，设置后条目从页面上消失后仍会保留在 Feed 中
+    #[serde(default)]
+    pub history: Option<HistoryConfig>,
+    /// 条目过滤规则，在 `feed.limit` 之前生效
+    #[serde(default)]
+    pub filter: Option<FilterConfig>,
+    /// 按顺序执行的后处理器，在 `filter` 之后、`feed.limit` 之前生效
+    #[serde(default)]
+    pub processors: Vec<ProcessorConfig>,
+    /// 链接清理，设置后删除文章链接和正文链接中的跟踪参数
+    #[serde(default)]
+    pub links: Option<LinksConfig>,
 }
 
 /// 插件元数据
@@ -49,6 +61,25 @@ pub struct HtmlSourceConfig {
     pub user_agent: Option<String>,
     #[serde(default)]
     pub request: RequestConfig,
+    /// 从页面脚本中提取内嵌的 JSON 数据，再按 JSON 路径解析
+    #[serde(default)]
+    pub embedded: Option<EmbeddedConfig>,
+}
+
+/// 内嵌 JSON 数据的位置
+///
+/// ```yaml
+/// embedded:
+///   selector: "script#__NEXT_DATA__"
+/// ```
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct EmbeddedConfig {
+    /// 包含数据的脚本选择器；只设置选择器时脚本的全部内容就是 JSON
+    #[serde(default)]
+    pub selector: Option<String>,
+    /// 被赋值的变量名，如 `__INITIAL_STATE__`（对应 `window.__INITIAL_STATE__ = {...}`）
+    #[serde(default)]
+    pub variable: Option<String>,
 }
 
 fn default_encoding() -> String {
@@ -101,40 +132,422 @@ fn default_timeout() -> u64 {
 }
 
 /// 解析器配置
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 pub struct ParserConfig {
+    /// 解析模式，默认使用 CSS 选择器
+    #[serde(default)]
+    pub mode: ParserMode,
+    /// CSS 模式下的列表页解析规则，其他模式不需要
+    #[serde(default)]
     pub list: ListParserConfig,
+    /// 多个列表页解析规则，结果按顺序拼接，不能与 `list` 同时使用
+    #[serde(default)]
+    pub lists: Vec<ListParserConfig>,
     #[serde(default)]
     pub content: Option<ContentParserConfig>,
+    #[serde(default)]
+    pub budget: ParseBudget,
+    /// GUID 生成策略，不设置时使用文章链接
+    #[serde(default)]
+    pub guid: Option<GuidConfig>,
+    /// 用于补全空字段的额外数据来源
+    #[serde(default)]
+    pub enrich: Vec<Enrichment>,
+    /// 没有提取到日期时的回退规则
+    #[serde(default)]
+    pub date_fallback: DateFallbackConfig,
+    /// `monitor` 模式下的监控规则
+    #[serde(default)]
+    pub monitor: Option<MonitorConfig>,
 }
 
-/// 列表页解析配置
+impl ParserConfig {
+    /// 生效的列表规则：设置了 `lists` 时为 `lists`，否则为单个 `list`
+    pub fn list_blocks(&self) -> &[ListParserConfig] {
+        match self.lists.is_empty() {
+            true => std::slice::from_ref(&self.list),
+            false => &self.lists,
+        }
+    }
+}
+
+/// 日期回退规则：选择器 → 链接中的日期 → 第一次看到的时间
+///
+/// ```yaml
+/// date_fallback:
+///   url_pattern: '/(?P<year>\d{4})/(?P<month>\d{2})/(?P<day>\d{2})/'
+///   first_seen: true
+/// ```
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct DateFallbackConfig {
+    /// 从文章链接中提取日期的正则，需包含命名分组 `year` 和 `month`，`day` 可选
+    #[serde(default)]
+    pub url_pattern: Option<String>,
+    /// 仍然没有日期时，使用服务器第一次看到该条目的时间
+    #[serde(default)]
+    pub first_seen: bool,
+}
+
+/// 解析模式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ParserMode {
+    /// 按 `list` 中的 CSS 选择器提取
+    #[default]
+    Css,
+    /// 从 `<script type="application/ld+json">` 中的 schema.org 数据提取
+    Jsonld,
+    /// 从 microformats2 的 h-entry（兼容 mf1 hentry）中提取
+    Microformats,
+    /// 监控 `monitor.selector` 中的内容，每次变化生成一个条目
+    Monitor,
+}
+
+/// 页面监控配置
+///
+/// ```yaml
+/// parser:
+///   mode: monitor
+///   monitor:
+///     selector: "main .pricing"
+///     cleanup_selectors: [".ad"]
+/// ```
 #[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MonitorConfig {
+    /// 被监控的区域，匹配多个元素时按顺序拼接
+    pub selector: String,
+    /// 比较之前移除的元素，例如时间戳、广告
+    #[serde(default)]
+    pub cleanup_selectors: Vec<String>,
+    /// 最多保留的变化记录数
+    #[serde(default = "default_monitor_max_changes")]
+    pub max_changes: usize,
+}
+
+fn default_monitor_max_changes() -> usize {
+    50
+}
+
+/// 补全字段的数据来源
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Enrichment {
+    /// 按链接匹配页面中的 JSON-LD 条目，填充 CSS 没有提取到的字段（仅 CSS 模式）
+    Jsonld,
+    /// 抓取文章详情页，用 OpenGraph 和 `<meta>` 标签填充空字段
+    Meta,
+    /// 抓取文章详情页，用 `<link rel="canonical">` 替换文章链接
+    Canonical,
+}
+
+/// GUID 生成策略
+///
+/// ```yaml
+/// guid:
+///   type: link
+///   strip_params: ["utm_*", "ref"]
+/// ```
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum GuidConfig {
+    /// 从页面中提取，`attr` 指定属性，否则取文本；没有匹配时回退到文章链接
+    Selector {
+        #[serde(deserialize_with = "deserialize_one_or_many")]
+        selector: Vec<String>,
+        #[serde(default)]
+        attr: Option<String>,
+    },
+    /// 规范化后的链接：去掉片段、删除指定参数并对查询参数排序
+    Link {
+        /// 要删除的查询参数，以 `*` 结尾时按前缀匹配
+        #[serde(default)]
+        strip_params: Vec<String>,
+    },
+    /// 所选字段内容的 SHA-256 哈希
+    Hash {
+        #[serde(default = "default_guid_hash_fields")]
+        fields: Vec<GuidField>,
+    },
+}
+
+/// 参与 GUID 哈希的文章字段
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GuidField {
+    Title,
+    Link,
+    Description,
+    Date,
+    Author,
+}
+
+fn default_guid_hash_fields() -> Vec<GuidField> {
+    vec![GuidField::Title, GuidField::Link]
+}
+
+/// 单次解析的资源预算，超出时中止解析
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ParseBudget {
+    /// 文档最大字节数
+    #[serde(default = "default_max_bytes")]
+    pub max_bytes: usize,
+    /// 解析最长耗时（毫秒）
+    #[serde(default = "default_max_duration_ms")]
+    pub max_duration_ms: u64,
+}
+
+impl Default for ParseBudget {
+    fn default() -> Self {
+        Self {
+            max_bytes: default_max_bytes(),
+            max_duration_ms: default_max_duration_ms(),
+        }
+    }
+}
+
+fn default_max_bytes() -> usize {
+    10 * 1024 * 1024
+}
+
+fn default_max_duration_ms() -> u64 {
+    10_000
+}
+
+/// 列表页解析配置
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 pub struct ListParserConfig {
     pub selector: String,
     #[serde(default)]
     pub item_selector: Option<String>,
-    pub link_selector: String,
-    pub title_selector: String,
+    pub link_selector: FieldSelector,
+    pub title_selector: FieldSelector,
     #[serde(default)]
-    pub description_selector: Option<String>,
+    pub description_selector: Option<FieldSelector>,
     #[serde(default)]
-    pub date_selector: Option<String>,
+    pub date_selector: Option<FieldSelector>,
     #[serde(default)]
     pub date_format: Option<String>,
     #[serde(default)]
-    pub author_selector: Option<String>,
+    pub author_selector: Option<FieldSelector>,
+    #[serde(default)]
+    pub category_selector: Option<FieldSelector>,
+    /// 分类分隔符，用于拆分 "a, b, c" 这样的分类字符串
+    #[serde(default)]
+    pub category_delimiter: Option<String>,
+    /// 附件（音频、视频等）选择器，URL 取自 `attr` 指定的属性，默认依次尝试 `src`、`href`
+    #[serde(default)]
+    pub enclosure_selector: Option<FieldSelector>,
+    /// 固定的附件 MIME 类型，设置后优先于属性和扩展名推断
+    #[serde(default)]
+    pub enclosure_type: Option<String>,
+    /// 附件元素上表示 MIME 类型的属性，默认为 `type`
+    #[serde(default)]
+    pub enclosure_type_attr: Option<String>,
+    /// 附件元素上表示字节长度的属性，默认为 `length`
+    #[serde(default)]
+    pub enclosure_length_attr: Option<String>,
+    /// 文章配图选择器，URL 取法与 `enclosure_selector` 相同
+    #[serde(default)]
+    pub image_selector: Option<FieldSelector>,
+    /// 自定义字段：字段名 → 选择器，结果保存在 `Article.extra` 中
+    #[serde(default)]
+    pub extra_fields: BTreeMap<String, FieldSelector>,
+}
+
+/// 字段选择器
+///
+/// 支持三种写法：
+/// - 单个选择器：`title_selector: "h2 a"`
+/// - 按顺序回退的选择器列表，第一个非空匹配生效：`title_selector: ["h2.featured a", "h2 a"]`
+/// - 完整形式，可声明 `required`、`default`、`attr`、`transforms` 与 `html`：
+///   `date_selector: { selector: ["time", ".date"], required: true }`
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[serde(from = "FieldSelectorRepr")]
+pub struct FieldSelector {
+    #[serde(rename = "selector")]
+    pub selectors: Vec<String>,
+    /// 为 true 时，缺少该字段的条目会被跳过
+    #[serde(default)]
+    pub required: bool,
+    /// 所有选择器都没有匹配时使用的默认值
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<String>,
+    /// 从元素的该属性取值，而不是文本内容
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attr: Option<String>,
+    /// 提取之后、构建文章之前依次执行的转换步骤
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub transforms: Vec<Transform>,
+    /// 为 true 时保留元素的内部 HTML（经过清理并转为绝对 URL），而不是纯文本
+    #[serde(default)]
+    pub html: bool,
+}
+
+impl FieldSelector {
+    pub fn new(selector: impl Into<String>) -> Self {
+        Self {
+            selectors: vec![selector.into()],
+            ..Default::default()
+        }
+    }
+}
+
+impl From<&str> for FieldSelector {
+    fn from(selector: &str) -> Self {
+        Self::new(selector)
+    }
+}
+
+impl From<String> for FieldSelector {
+    fn from(selector: String) -> Self {
+        Self::new(selector)
+    }
+}
+
+impl From<Vec<String>> for FieldSelector {
+    fn from(selectors: Vec<String>) -> Self {
+        Self {
+            selectors,
+            ..Default::default()
+        }
+    }
+}
+
+/// 字段值转换步骤
+///
+/// ```yaml
+/// transforms:
+///   - type: regex
+///     pattern: "Published on (\\d{4}-\\d{2}-\\d{2})"
+///   - type: replace
+///     pattern: "^Posted by\\s+"
+///     replacement: ""
+///   - type: trim
+/// ```
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Transform {
+    /// 正则捕获，取指定分组；未指定时有分组取第 1 组，否则取整个匹配。不匹配时结果为空
+    Regex {
+        pattern: String,
+        #[serde(default)]
+        group: Option<usize>,
+    },
+    /// 正则替换，`replacement` 中可以使用 `$1`、`${name}` 引用分组
+    Replace {
+        pattern: String,
+        #[serde(default)]
+        replacement: String,
+    },
+    /// 去除首尾空白
+    Trim,
+    /// 转为小写
+    Lowercase,
+    /// 转为大写
+    Uppercase,
+    /// 添加前缀
+    Prefix { value: String },
+    /// 添加后缀
+    Suffix { value: String },
+    /// 按字符截取 `[start, end)`
+    Substring {
+        #[serde(default)]
+        start: usize,
+        #[serde(default)]
+        end: Option<usize>,
+    },
+}
+
+/// `FieldSelector` 在 YAML 中的各种写法
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum FieldSelectorRepr {
+    Single(String),
+    Chain(Vec<String>),
+    Detailed(DetailedFieldSelector),
+}
+
+#[derive(Deserialize)]
+struct DetailedFieldSelector {
+    selector: OneOrMany,
+    #[serde(default)]
+    required: bool,
+    #[serde(default)]
+    default: Option<String>,
+    #[serde(default)]
+    attr: Option<String>,
     #[serde(default)]
-    pub category_selector: Option<String>,
+    transforms: Vec<Transform>,
+    #[serde(default)]
+    html: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum OneOrMany {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl From<OneOrMany> for Vec<String> {
+    fn from(value: OneOrMany) -> Self {
+        match value {
+            OneOrMany::One(s) => vec![s],
+            OneOrMany::Many(v) => v,
+        }
+    }
+}
+
+impl From<FieldSelectorRepr> for FieldSelector {
+    fn from(repr: FieldSelectorRepr) -> Self {
+        match repr {
+            FieldSelectorRepr::Single(s) => FieldSelector::new(s),
+            FieldSelectorRepr::Chain(v) => FieldSelector::from(v),
+            FieldSelectorRepr::Detailed(d) => FieldSelector {
+                selectors: d.selector.into(),
+                required: d.required,
+                default: d.default,
+                attr: d.attr,
+                transforms: d.transforms,
+                html: d.html,
+            },
+        }
+    }
 }
 
 /// 内容页解析配置
-#[derive(Debug, Clone, Deserialize, Serialize)]
+///
+/// 配置后会逐条抓取文章详情页，提取的正文覆盖列表页中的 `content`。
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 pub struct ContentParserConfig {
+    #[serde(default)]
+    pub mode: ContentMode,
+    /// 内容页容器选择器，为空时使用整个文档
+    #[serde(default)]
     pub selector: String,
+    /// 正文选择器（相对于容器），`selector` 模式下必填
+    #[serde(default)]
     pub content_selector: String,
     #[serde(default)]
     pub cleanup_selectors: Vec<String>,
+    /// 同时抓取的详情页数量
+    #[serde(default = "default_content_concurrency")]
+    pub concurrency: usize,
+}
+
+/// 正文提取方式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ContentMode {
+    /// 按选择器提取，匹配不到时回退到自动提取
+    #[default]
+    Selector,
+    /// 按文本密度和链接比例自动识别正文
+    Auto,
+}
+
+fn default_content_concurrency() -> usize {
+    4
 }
 
 /// 缓存配置
@@ -175,6 +588,30 @@ pub struct FeedConfig {
     pub format: String,
     #[serde(default = "default_limit")]
     pub limit: usize,
+    /// 按 GUID 或规范化链接去掉重复条目，只保留第一次出现的条目
+    #[serde(default = "default_dedupe")]
+    pub dedupe: bool,
+    /// 条目排序方式，默认保持页面顺序
+    #[serde(default)]
+    pub sort: Option<FeedSort>,
+    /// 条目标题模板，如 `[{category}] {title}`
+    #[serde(default)]
+    pub title_template: Option<String>,
+    /// 条目描述模板，如 `{author} · {date} · {description}`
+    #[serde(default)]
+    pub description_template: Option<String>,
+}
+
+/// 条目排序方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FeedSort {
+    /// 按发布时间从新到旧，没有日期的条目留在原来的位置
+    DateDesc,
+}
+
+fn default_dedupe() -> bool {
+    true
 }
 
 fn default_language() -> String {
@@ -189,23 +626,338 @@ fn default_limit() -> usize {
     20
 }
 
+/// 链接清理配置
+///
+/// ```yaml
+/// links:
+///   strip_tracking: true
+///   strip_params: ["from", "share_*"]
+/// ```
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct LinksConfig {
+    /// 删除内置列表中的跟踪参数和会话 ID（`utm_*`、`fbclid`、`spm`、`jsessionid` 等）
+    #[serde(default = "default_strip_tracking")]
+    pub strip_tracking: bool,
+    /// 额外删除的参数名，以 `*` 结尾时按前缀匹配，不区分大小写
+    #[serde(default)]
+    pub strip_params: Vec<String>,
+}
+
+impl Default for LinksConfig {
+    fn default() -> Self {
+        Self {
+            strip_tracking: default_strip_tracking(),
+            strip_params: Vec::new(),
+        }
+    }
+}
+
+fn default_strip_tracking() -> bool {
+    true
+}
+
+/// 条目历史配置
+///
+/// ```yaml
+/// history:
+///   max_items: 200
+///   max_age_days: 30
+/// ```
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct HistoryConfig {
+    /// 最多保留的条目数
+    #[serde(default = "default_history_max_items")]
+    pub max_items: usize,
+    /// 条目最后一次出现在页面上之后保留的天数，不设置时只按数量淘汰
+    #[serde(default)]
+    pub max_age_days: Option<u64>,
+}
+
+impl Default for HistoryConfig {
+    fn default() -> Self {
+        Self {
+            max_items: default_history_max_items(),
+            max_age_days: None,
+        }
+    }
+}
+
+fn default_history_max_items() -> usize {
+    200
+}
+
+/// 条目过滤配置
+///
+/// ```yaml
+/// filter:
+///   match: any
+///   include:
+///     - field: [title, category]
+///       contains: "rust"
+///   exclude:
+///     - field: title
+///       regex: "^\\[(Sponsored|广告)\\]"
+/// ```
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct FilterConfig {
+    /// 多条规则的组合方式：`any` 任一规则匹配即可，`all` 需要全部匹配
+    #[serde(default, rename = "match")]
+    pub match_mode: FilterMatch,
+    /// 设置后只保留匹配的条目
+    #[serde(default)]
+    pub include: Vec<FilterRule>,
+    /// 移除匹配的条目，优先于 `include`
+    #[serde(default)]
+    pub exclude: Vec<FilterRule>,
+}
+
+/// 过滤规则的组合方式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FilterMatch {
+    All,
+    #[default]
+    Any,
+}
+
+/// 单条过滤规则，`contains` 和 `regex` 二选一
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct FilterRule {
+    /// 匹配的字段，任一字段匹配即视为规则匹配，默认为标题和描述
+    #[serde(
+        default = "default_filter_fields",
+        alias = "field",
+        deserialize_with = "deserialize_filter_fields"
+    )]
+    pub fields: Vec<FilterField>,
+    /// 子串匹配
+    #[serde(default)]
+    pub contains: Option<String>,
+    /// 正则匹配
+    #[serde(default)]
+    pub regex: Option<String>,
+    /// 是否区分大小写，默认不区分
+    #[serde(default)]
+    pub case_sensitive: bool,
+}
+
+/// 可过滤的文章字段，自定义字段写作 `extra.<name>`
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum FilterField {
+    Title,
+    Description,
+    Author,
+    Category,
+    Link,
+    Extra(String),
+}
+
+impl TryFrom<String> for FilterField {
+    type Error = String;
+
+    fn try_from(name: String) -> Result<Self, Self::Error> {
+        match name.as_str() {
+            "title" => Ok(Self::Title),
+            "description" => Ok(Self::Description),
+            "author" => Ok(Self::Author),
+            "category" => Ok(Self::Category),
+            "link" => Ok(Self::Link),
+            other => match other.strip_prefix("extra.") {
+                Some(extra) if !extra.is_empty() => Ok(Self::Extra(extra.to_string())),
+                _ => Err(format!(
+                    "unknown filter field '{}', expected title, description, author, category, link or extra.<name>",
+                    other
+                )),
+            },
+        }
+    }
+}
+
+impl From<FilterField> for String {
+    fn from(field: FilterField) -> Self {
+        match field {
+            FilterField::Title => "title".to_string(),
+            FilterField::Description => "description".to_string(),
+            FilterField::Author => "author".to_string(),
+            FilterField::Category => "category".to_string(),
+            FilterField::Link => "link".to_string(),
+            FilterField::Extra(name) => format!("extra.{}", name),
+        }
+    }
+}
+
+fn default_filter_fields() -> Vec<FilterField> {
+    vec![FilterField::Title, FilterField::Description]
+}
+
+/// 单个字段或字段列表
+fn deserialize_filter_fields<'de, D>(deserializer: D) -> Result<Vec<FilterField>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Fields {
+        One(FilterField),
+        Many(Vec<FilterField>),
+    }
+
+    Ok(match Fields::deserialize(deserializer)? {
+        Fields::One(field) => vec![field],
+        Fields::Many(fields) => fields,
+    })
+}
+
+/// 后处理器配置
+///
+/// `type` 选择处理器，其余字段作为该处理器的参数：
+///
+/// ```yaml
+/// processors:
+///   - type: filter
+///     exclude: [{ field: title, contains: "广告" }]
+///   - type: limit
+///     count: 10
+/// ```
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ProcessorConfig {
+    #[serde(rename = "type")]
+    pub kind: String,
+    #[serde(flatten)]
+    pub options: serde_yaml::Mapping,
+}
+
+impl ProcessorConfig {
+    /// 把处理器参数反序列化为具体类型
+    pub fn options<T: serde::de::DeserializeOwned>(&self) -> crate::error::Result<T> {
+        serde_yaml::from_value(serde_yaml::Value::Mapping(self.options.clone())).map_err(|e| {
+            crate::error::RssHubError::Config(format!("Invalid options for processor '{}': {}", self.kind, e))
+        })
+    }
+}
+
 /// 解析后的文章条目
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// JSON 中的分类写在 `categories` 中，同时把第一个分类写入旧版的 `category` 字段；
+/// 读取时合并这两个字段
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(from = "ArticleRepr", into = "ArticleRepr")]
 pub struct Article {
     pub title: String,
     pub link: String,
-    #[serde(default)]
     pub description: Option<String>,
-    #[serde(default)]
     pub content: Option<String>,
-    #[serde(default)]
     pub pub_date: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(default)]
     pub author: Option<String>,
+    /// 分类/标签
+    pub categories: Vec<String>,
+    pub guid: Option<String>,
+    /// 附件（音频、视频等）
+    pub enclosure: Option<Enclosure>,
+    /// 文章配图 URL
+    pub image: Option<String>,
+    /// `extra_fields` 提取的自定义字段
+    pub extra: BTreeMap<String, String>,
+}
+
+/// `Article` 的 JSON 形式，兼容旧版单个 `category` 字段
+#[derive(Serialize, Deserialize)]
+struct ArticleRepr {
+    title: String,
+    link: String,
     #[serde(default)]
-    pub category: Option<String>,
+    description: Option<String>,
     #[serde(default)]
-    pub guid: Option<String>,
+    content: Option<String>,
+    #[serde(default)]
+    pub_date: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default)]
+    author: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    category: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_categories")]
+    categories: Vec<String>,
+    #[serde(default)]
+    guid: Option<String>,
+    #[serde(default)]
+    enclosure: Option<Enclosure>,
+    #[serde(default)]
+    image: Option<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    extra: BTreeMap<String, String>,
+}
+
+impl From<ArticleRepr> for Article {
+    fn from(repr: ArticleRepr) -> Self {
+        let mut categories = repr.categories;
+        if let Some(category) = repr.category {
+            if !categories.contains(&category) {
+                categories.insert(0, category);
+            }
+        }
+
+        Self {
+            title: repr.title,
+            link: repr.link,
+            description: repr.description,
+            content: repr.content,
+            pub_date: repr.pub_date,
+            author: repr.author,
+            categories,
+            guid: repr.guid,
+            enclosure: repr.enclosure,
+            image: repr.image,
+            extra: repr.extra,
+        }
+    }
+}
+
+impl From<Article> for ArticleRepr {
+    fn from(article: Article) -> Self {
+        Self {
+            title: article.title,
+            link: article.link,
+            description: article.description,
+            content: article.content,
+            pub_date: article.pub_date,
+            author: article.author,
+            category: article.categories.first().cloned(),
+            categories: article.categories,
+            guid: article.guid,
+            enclosure: article.enclosure,
+            image: article.image,
+            extra: article.extra,
+        }
+    }
+}
+
+/// 文章附件
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Enclosure {
+    pub url: String,
+    /// MIME 类型，如 `audio/mpeg`
+    pub mime_type: String,
+    /// 字节长度，未知时为 `None`
+    #[serde(default)]
+    pub length: Option<u64>,
+}
+
+/// 分类既可以是单个字符串，也可以是字符串列表
+fn deserialize_categories<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let value: Option<OneOrMany> = Option::deserialize(deserializer)?;
+    Ok(value.map(Vec::from).unwrap_or_default())
+}
+
+/// 单个字符串或字符串列表
+fn deserialize_one_or_many<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    OneOrMany::deserialize(deserializer).map(Vec::from)
 }
 
 /// RSS Feed 数据
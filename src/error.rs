@@ -31,6 +31,9 @@ pub enum RssHubError {
 
     #[error("Invalid URL: {0}")]
     InvalidUrl(String),
+
+    #[error("Storage error: {0}")]
+    StorageError(#[from] sled::Error),
 }
 
 pub type Result<T> = std::result::Result<T, RssHubError>;
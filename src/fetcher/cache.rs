@@ -6,6 +6,13 @@ use tokio::sync::RwLock;
 pub struct CacheEntry {
     pub content: String,
     pub created_at: Instant,
+    pub ttl: Duration,
+}
+
+impl CacheEntry {
+    fn is_fresh(&self) -> bool {
+        self.created_at.elapsed() < self.ttl
+    }
 }
 
 pub struct MemoryCache {
@@ -25,18 +32,24 @@ impl MemoryCache {
     pub async fn get(&self, key: &str) -> Option<String> {
         let entries = self.entries.read().await;
         if let Some(entry) = entries.get(key) {
-            if entry.created_at.elapsed() < self.ttl {
+            if entry.is_fresh() {
                 return Some(entry.content.clone());
             }
         }
         None
     }
 
-    /// 设置缓存
+    /// 设置缓存，使用默认过期时间
     pub async fn set(&self, key: String, content: String) {
+        self.set_with_ttl(key, content, self.ttl).await;
+    }
+
+    /// 设置缓存，并指定该条目的过期时间
+    pub async fn set_with_ttl(&self, key: String, content: String, ttl: Duration) {
         let entry = CacheEntry {
             content,
             created_at: Instant::now(),
+            ttl,
         };
         let mut entries = self.entries.write().await;
         entries.insert(key, entry);
@@ -45,7 +58,24 @@ impl MemoryCache {
     /// 清除过期缓存
     pub async fn cleanup(&self) {
         let mut entries = self.entries.write().await;
-        entries.retain(|_, entry| entry.created_at.elapsed() < self.ttl);
+        entries.retain(|_, entry| entry.is_fresh());
+    }
+
+    /// 清除过期缓存；仍超过 `max_entries` 时按创建时间移除最早的条目
+    pub async fn shrink_to(&self, max_entries: usize) {
+        let mut entries = self.entries.write().await;
+        entries.retain(|_, entry| entry.is_fresh());
+
+        if entries.len() > max_entries {
+            let mut by_age: Vec<_> = entries
+                .iter()
+                .map(|(key, entry)| (entry.created_at, key.clone()))
+                .collect();
+            by_age.sort();
+            for (_, key) in by_age.into_iter().take(entries.len() - max_entries) {
+                entries.remove(&key);
+            }
+        }
     }
 
     /// 清除指定缓存
@@ -104,6 +134,18 @@ mod tests {
         assert!(value.is_none());
     }
 
+    #[tokio::test]
+    async fn test_cache_entry_ttl() {
+        let cache = MemoryCache::new(3600);
+
+        cache.set_with_ttl("key1".to_string(), "value1".to_string(), Duration::ZERO).await;
+        cache.set("key2".to_string(), "value2".to_string()).await;
+
+        // 条目自己的过期时间优先于默认值
+        assert!(cache.get("key1").await.is_none());
+        assert!(cache.get("key2").await.is_some());
+    }
+
     #[tokio::test]
     async fn test_cache_invalidate() {
         let cache = MemoryCache::new(3600);
@@ -130,6 +172,22 @@ mod tests {
         assert!(cache.is_empty().await);
     }
 
+    #[tokio::test]
+    async fn test_cache_shrink_to() {
+        let cache = MemoryCache::new(3600);
+
+        for key in ["key1", "key2", "key3"] {
+            cache.set(key.to_string(), "value".to_string()).await;
+            tokio::time::sleep(Duration::from_millis(2)).await;
+        }
+        cache.shrink_to(2).await;
+
+        // 未过期时移除最早创建的条目
+        assert_eq!(cache.len().await, 2);
+        assert!(cache.get("key1").await.is_none());
+        assert!(cache.get("key3").await.is_some());
+    }
+
     #[tokio::test]
     async fn test_cache_cleanup() {
         let cache = MemoryCache::new(1); // 1秒 TTL
@@ -1,5 +1,5 @@
 use crate::error::{Result, RssHubError};
-use crate::config::types::SourceConfig;
+use crate::config::types::{RequestConfig, SourceConfig};
 use reqwest::Client;
 use std::time::Duration;
 use encoding_rs::UTF_8;
@@ -34,6 +34,11 @@ impl HttpFetcher {
             SourceConfig::Xml(cfg) => (&cfg.url, &cfg.request, "utf-8".to_string()),
         };
 
+        self.fetch_url(url, config, &encoding).await
+    }
+
+    /// 按请求配置获取任意页面，并按指定编码转换为 UTF-8
+    pub async fn fetch_url(&self, url: &str, config: &RequestConfig, encoding: &str) -> Result<String> {
         let mut request = self.client.request(
             reqwest::Method::from_bytes(config.method.as_bytes())
                 .map_err(|_| RssHubError::Config(format!("Invalid HTTP method: {}", config.method)))?,
@@ -1,5 +1,9 @@
 use crate::config::types::{Article, RssFeed};
+use crate::generator::{has_extra, EXTRA_NAMESPACE, EXTRA_PREFIX};
+use crate::parser::media::guess_mime_type;
+use atom_syndication::extension::{Extension, ExtensionMap};
 use atom_syndication::{Feed, Link, Person, Content};
+use std::collections::BTreeMap;
 
 pub struct AtomGenerator;
 
@@ -17,18 +21,20 @@ impl AtomGenerator {
             length: None,
         }]);
 
-        // 设置更新时间
-        if let Some(latest_article) = feed.articles.first() {
-            if let Some(pub_date) = latest_article.pub_date {
-                atom_feed.set_updated(pub_date);
-            }
+        // 更新时间取最新的发布时间，不依赖条目顺序
+        if let Some(updated) = feed.articles.iter().filter_map(|article| article.pub_date).max() {
+            atom_feed.set_updated(updated);
+        }
+
+        if has_extra(feed) {
+            atom_feed.set_namespaces(BTreeMap::from([(EXTRA_PREFIX.to_string(), EXTRA_NAMESPACE.to_string())]));
         }
 
         // 添加文章
         let entries: Vec<atom_syndication::Entry> = feed
             .articles
             .iter()
-            .map(|article| Self::article_to_entry(article))
+            .map(Self::article_to_entry)
             .collect();
 
         atom_feed.set_entries(entries);
@@ -44,27 +50,55 @@ impl AtomGenerator {
         entry.id(article.guid.as_ref().unwrap_or(&article.link).clone());
 
         // 链接
-        entry.links(vec![Link {
+        let mut links = vec![Link {
             href: article.link.clone(),
             rel: "alternate".to_string(),
             mime_type: None,
             title: None,
             hreflang: None,
             length: None,
-        }]);
+        }];
+
+        // 附件和配图
+        if let Some(ref enclosure) = article.enclosure {
+            links.push(Link {
+                href: enclosure.url.clone(),
+                rel: "enclosure".to_string(),
+                mime_type: Some(enclosure.mime_type.clone()),
+                title: None,
+                hreflang: None,
+                length: enclosure.length.map(|length| length.to_string()),
+            });
+        }
+        if let Some(ref image) = article.image {
+            if links.iter().all(|link| link.href != *image) {
+                links.push(Link {
+                    href: image.clone(),
+                    rel: "enclosure".to_string(),
+                    mime_type: Some(guess_mime_type(image).to_string()),
+                    title: None,
+                    hreflang: None,
+                    length: None,
+                });
+            }
+        }
+
+        entry.links(links);
 
         // 内容
         if let Some(ref content) = article.content {
-            let mut content_obj = Content::default();
-            content_obj.value = Some(content.clone());
-            content_obj.content_type = Some("html".to_string());
-            entry.content(content_obj);
+            entry.content(Content {
+                value: Some(content.clone()),
+                content_type: Some("html".to_string()),
+                ..Default::default()
+            });
         } else if let Some(ref desc) = article.description {
             // 如果没有content，使用description作为content
-            let mut content_obj = Content::default();
-            content_obj.value = Some(desc.clone());
-            content_obj.content_type = Some("text".to_string());
-            entry.content(content_obj);
+            entry.content(Content {
+                value: Some(desc.clone()),
+                content_type: Some("text".to_string()),
+                ..Default::default()
+            });
         }
 
         // 摘要
@@ -75,7 +109,7 @@ impl AtomGenerator {
         // 发布日期和更新时间
         if let Some(pub_date) = article.pub_date {
             // 转换为FixedOffset
-            let fixed_date: chrono::DateTime<chrono::FixedOffset> = pub_date.clone().into();
+            let fixed_date: chrono::DateTime<chrono::FixedOffset> = pub_date.into();
             entry.published(Some(fixed_date));
             entry.updated(fixed_date);
         }
@@ -90,12 +124,35 @@ impl AtomGenerator {
         }
 
         // 分类
-        if let Some(ref category) = article.category {
-            entry.categories(vec![atom_syndication::Category {
-                term: category.clone(),
-                scheme: None,
-                label: None,
-            }]);
+        entry.categories(
+            article
+                .categories
+                .iter()
+                .map(|category| atom_syndication::Category {
+                    term: category.clone(),
+                    scheme: None,
+                    label: None,
+                })
+                .collect::<Vec<_>>(),
+        );
+
+        // 自定义字段，如 `<rsshub:score>42</rsshub:score>`
+        if !article.extra.is_empty() {
+            let elements = article
+                .extra
+                .iter()
+                .map(|(name, value)| {
+                    let extension = Extension {
+                        name: format!("{}:{}", EXTRA_PREFIX, name),
+                        value: Some(value.clone()),
+                        ..Default::default()
+                    };
+                    (name.clone(), vec![extension])
+                })
+                .collect();
+            let mut extensions = ExtensionMap::new();
+            extensions.insert(EXTRA_PREFIX.to_string(), elements);
+            entry.extensions(extensions);
         }
 
         entry.build()
@@ -105,6 +162,7 @@ impl AtomGenerator {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::types::Enclosure;
     use chrono::Utc;
 
     #[test]
@@ -123,6 +181,54 @@ mod tests {
         assert!(atom.contains("https://example.com"));
     }
 
+    #[test]
+    fn test_updated_is_latest_date() {
+        let now = Utc::now();
+        let dated = |title: &str, date| Article {
+            title: title.to_string(),
+            link: format!("https://example.com/{}", title),
+            pub_date: date,
+            ..Default::default()
+        };
+        let feed = RssFeed {
+            title: "Test Feed".to_string(),
+            description: "Test Description".to_string(),
+            link: "https://example.com".to_string(),
+            language: "en".to_string(),
+            articles: vec![
+                dated("undated", None),
+                dated("old", Some(now - chrono::Duration::days(3))),
+                dated("new", Some(now)),
+            ],
+        };
+
+        let atom: Feed = AtomGenerator::generate(&feed).parse().unwrap();
+
+        assert_eq!(atom.updated().timestamp(), now.timestamp());
+    }
+
+    #[test]
+    fn test_extra_fields_as_extensions() {
+        let mut article = Article {
+            title: "Show HN".to_string(),
+            link: "https://example.com/item".to_string(),
+            ..Default::default()
+        };
+        article.extra.insert("score".to_string(), "42".to_string());
+        let feed = RssFeed {
+            title: "Test Feed".to_string(),
+            description: "Test Description".to_string(),
+            link: "https://example.com".to_string(),
+            language: "en".to_string(),
+            articles: vec![article],
+        };
+
+        let atom = AtomGenerator::generate(&feed);
+
+        assert!(atom.contains(r#"xmlns:rsshub="urn:rust-rsshub:extra""#));
+        assert!(atom.contains("<rsshub:score>42</rsshub:score>"));
+    }
+
     #[test]
     fn test_article_with_all_fields() {
         let article = Article {
@@ -132,8 +238,15 @@ mod tests {
             content: Some("<p>Test content</p>".to_string()),
             pub_date: Some(Utc::now()),
             author: Some("John Doe".to_string()),
-            category: Some("Tech".to_string()),
+            categories: vec!["Tech".to_string(), "Rust".to_string()],
             guid: Some("unique-id-123".to_string()),
+            enclosure: Some(Enclosure {
+                url: "https://example.com/episode.mp3".to_string(),
+                mime_type: "audio/mpeg".to_string(),
+                length: Some(1024),
+            }),
+            image: Some("https://example.com/cover.png".to_string()),
+            extra: Default::default(),
         };
 
         let entry = AtomGenerator::article_to_entry(&article);
@@ -142,6 +255,46 @@ mod tests {
         assert_eq!(entry.id(), "unique-id-123");
         assert!(!entry.links().is_empty());
         assert_eq!(entry.links()[0].href, "https://example.com/article");
+
+        let categories: Vec<_> = entry.categories().iter().map(|c| c.term()).collect();
+        assert_eq!(categories, vec!["Tech", "Rust"]);
+
+        let enclosures: Vec<_> = entry
+            .links()
+            .iter()
+            .filter(|link| link.rel() == "enclosure")
+            .map(|link| (link.href(), link.mime_type(), link.length()))
+            .collect();
+        assert_eq!(
+            enclosures,
+            vec![
+                ("https://example.com/episode.mp3", Some("audio/mpeg"), Some("1024")),
+                ("https://example.com/cover.png", Some("image/png"), None),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_html_content_type() {
+        let article = Article {
+            title: "Test Article".to_string(),
+            link: "https://example.com/article".to_string(),
+            description: Some("Intro".to_string()),
+            content: Some("<p>Intro</p>".to_string()),
+            pub_date: None,
+            author: None,
+            categories: Vec::new(),
+            guid: None,
+            enclosure: None,
+            image: None,
+            extra: Default::default(),
+        };
+
+        let entry = AtomGenerator::article_to_entry(&article);
+        let content = entry.content().unwrap();
+
+        assert_eq!(content.content_type(), Some("html"));
+        assert_eq!(content.value(), Some("<p>Intro</p>"));
     }
 
     #[test]
@@ -153,8 +306,11 @@ mod tests {
             content: None,
             pub_date: None,
             author: None,
-            category: None,
+            categories: Vec::new(),
             guid: None,
+            enclosure: None,
+            image: None,
+            extra: Default::default(),
         };
 
         let entry = AtomGenerator::article_to_entry(&article);
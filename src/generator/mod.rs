@@ -3,3 +3,15 @@ pub mod atom;
 
 pub use rss::RssGenerator;
 pub use atom::AtomGenerator;
+
+use crate::config::types::RssFeed;
+
+/// RSS/Atom 中自定义字段扩展元素的命名空间前缀
+pub const EXTRA_PREFIX: &str = "rsshub";
+/// 自定义字段扩展元素的命名空间
+pub const EXTRA_NAMESPACE: &str = "urn:rust-rsshub:extra";
+
+/// 是否有条目带有自定义字段，没有时不声明命名空间
+fn has_extra(feed: &RssFeed) -> bool {
+    feed.articles.iter().any(|article| !article.extra.is_empty())
+}
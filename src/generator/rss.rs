@@ -1,5 +1,9 @@
 use crate::config::types::{Article, RssFeed};
-use rss::{ChannelBuilder, Item, GuidBuilder};
+use crate::generator::{has_extra, EXTRA_NAMESPACE, EXTRA_PREFIX};
+use crate::parser::media::guess_mime_type;
+use rss::extension::{Extension, ExtensionMap};
+use rss::{ChannelBuilder, EnclosureBuilder, Item, GuidBuilder};
+use std::collections::BTreeMap;
 
 pub struct RssGenerator;
 
@@ -8,14 +12,20 @@ impl RssGenerator {
         let items: Vec<Item> = feed
             .articles
             .iter()
-            .map(|article| Self::article_to_item(article))
+            .map(Self::article_to_item)
             .collect();
 
+        let mut namespaces = BTreeMap::new();
+        if has_extra(feed) {
+            namespaces.insert(EXTRA_PREFIX.to_string(), EXTRA_NAMESPACE.to_string());
+        }
+
         let channel = ChannelBuilder::default()
             .title(&feed.title)
             .link(&feed.link)
             .description(&feed.description)
             .language(Some(feed.language.clone()))
+            .namespaces(namespaces)
             .items(items)
             .build();
 
@@ -49,20 +59,64 @@ impl RssGenerator {
         }
 
         // 分类
-        if let Some(ref category) = article.category {
-            item.set_categories(vec![rss::Category {
-                name: category.clone(),
-                domain: None,
-            }]);
+        item.set_categories(
+            article
+                .categories
+                .iter()
+                .map(|category| rss::Category {
+                    name: category.clone(),
+                    domain: None,
+                })
+                .collect::<Vec<_>>(),
+        );
+
+        // 附件；RSS 每个条目只能有一个附件，没有附件时使用配图
+        if let Some(ref enclosure) = article.enclosure {
+            item.set_enclosure(
+                EnclosureBuilder::default()
+                    .url(enclosure.url.clone())
+                    .mime_type(enclosure.mime_type.clone())
+                    .length(enclosure.length.unwrap_or(0).to_string())
+                    .build(),
+            );
+        } else if let Some(ref image) = article.image {
+            item.set_enclosure(
+                EnclosureBuilder::default()
+                    .url(image.clone())
+                    .mime_type(guess_mime_type(image).to_string())
+                    .length("0".to_string())
+                    .build(),
+            );
         }
 
-        // GUID
+        // GUID；只有 http(s) 链接才是永久链接
+        let value = article.guid.as_ref().unwrap_or(&article.link).clone();
+        let permalink = value.starts_with("http://") || value.starts_with("https://");
         let guid = GuidBuilder::default()
-            .value(article.guid.as_ref().unwrap_or(&article.link).clone())
-            .permalink(false)
+            .value(value)
+            .permalink(permalink)
             .build();
         item.set_guid(guid);
 
+        // 自定义字段，如 `<rsshub:score>42</rsshub:score>`
+        if !article.extra.is_empty() {
+            let elements = article
+                .extra
+                .iter()
+                .map(|(name, value)| {
+                    let extension = Extension {
+                        name: format!("{}:{}", EXTRA_PREFIX, name),
+                        value: Some(value.clone()),
+                        ..Default::default()
+                    };
+                    (name.clone(), vec![extension])
+                })
+                .collect();
+            let mut extensions = ExtensionMap::new();
+            extensions.insert(EXTRA_PREFIX.to_string(), elements);
+            item.set_extensions(extensions);
+        }
+
         item
     }
 }
@@ -70,6 +124,7 @@ impl RssGenerator {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::types::Enclosure;
     use chrono::Utc;
 
     #[test]
@@ -89,6 +144,28 @@ mod tests {
         assert!(rss.contains("<link>https://example.com</link>"));
     }
 
+    #[test]
+    fn test_extra_fields_as_extensions() {
+        let mut article = Article {
+            title: "Show HN".to_string(),
+            link: "https://example.com/item".to_string(),
+            ..Default::default()
+        };
+        article.extra.insert("score".to_string(), "42".to_string());
+        let feed = RssFeed {
+            title: "Test Feed".to_string(),
+            description: "Test Description".to_string(),
+            link: "https://example.com".to_string(),
+            language: "en".to_string(),
+            articles: vec![article],
+        };
+
+        let rss = RssGenerator::generate(&feed);
+
+        assert!(rss.contains(r#"xmlns:rsshub="urn:rust-rsshub:extra""#));
+        assert!(rss.contains("<rsshub:score>42</rsshub:score>"));
+    }
+
     #[test]
     fn test_article_with_all_fields() {
         let article = Article {
@@ -98,8 +175,15 @@ mod tests {
             content: Some("<p>Test content</p>".to_string()),
             pub_date: Some(Utc::now()),
             author: Some("John Doe".to_string()),
-            category: Some("Tech".to_string()),
+            categories: vec!["Tech".to_string(), "Rust".to_string()],
             guid: Some("unique-id-123".to_string()),
+            enclosure: Some(Enclosure {
+                url: "https://example.com/episode.mp3".to_string(),
+                mime_type: "audio/mpeg".to_string(),
+                length: Some(1024),
+            }),
+            image: Some("https://example.com/cover.png".to_string()),
+            extra: Default::default(),
         };
 
         let item = RssGenerator::article_to_item(&article);
@@ -108,6 +192,77 @@ mod tests {
         assert_eq!(item.link(), Some("https://example.com/article"));
         assert_eq!(item.description(), Some("Test description"));
         assert_eq!(item.author(), Some("John Doe"));
+
+        let categories: Vec<_> = item.categories().iter().map(|c| c.name()).collect();
+        assert_eq!(categories, vec!["Tech", "Rust"]);
+
+        let enclosure = item.enclosure().unwrap();
+        assert_eq!(enclosure.url(), "https://example.com/episode.mp3");
+        assert_eq!(enclosure.mime_type(), "audio/mpeg");
+        assert_eq!(enclosure.length(), "1024");
+
+        let guid = item.guid().unwrap();
+        assert_eq!(guid.value(), "unique-id-123");
+        assert!(!guid.is_permalink());
+    }
+
+    #[test]
+    fn test_url_guid_is_permalink() {
+        let article = Article {
+            title: "Post".to_string(),
+            link: "https://example.com/post?utm_source=rss".to_string(),
+            guid: Some("https://example.com/post".to_string()),
+            ..Default::default()
+        };
+
+        let guid = RssGenerator::article_to_item(&article).guid().cloned().unwrap();
+        assert_eq!(guid.value(), "https://example.com/post");
+        assert!(guid.is_permalink());
+    }
+
+    #[test]
+    fn test_image_used_as_enclosure() {
+        let article = Article {
+            title: "Photo".to_string(),
+            link: "https://example.com/photo".to_string(),
+            image: Some("https://example.com/photo.jpg".to_string()),
+            ..Default::default()
+        };
+
+        let item = RssGenerator::article_to_item(&article);
+
+        let enclosure = item.enclosure().unwrap();
+        assert_eq!(enclosure.url(), "https://example.com/photo.jpg");
+        assert_eq!(enclosure.mime_type(), "image/jpeg");
+        assert_eq!(enclosure.length(), "0");
+    }
+
+    #[test]
+    fn test_html_content_emitted_as_content_encoded() {
+        let feed = RssFeed {
+            title: "Test Feed".to_string(),
+            description: "Test Description".to_string(),
+            link: "https://example.com".to_string(),
+            language: "en".to_string(),
+            articles: vec![Article {
+                title: "Test Article".to_string(),
+                link: "https://example.com/article".to_string(),
+                description: Some("Intro".to_string()),
+                content: Some(r#"<p>Intro <img src="https://example.com/a.png"></p>"#.to_string()),
+                pub_date: None,
+                author: None,
+                categories: Vec::new(),
+                guid: None,
+                enclosure: None,
+                image: None,
+                extra: Default::default(),
+            }],
+        };
+
+        let rss = RssGenerator::generate(&feed);
+
+        assert!(rss.contains(r#"xmlns:content="http://purl.org/rss/1.0/modules/content/""#));
+        assert!(rss.contains(r#"<content:encoded><![CDATA[<p>Intro <img src="https://example.com/a.png"></p>]]></content:encoded>"#));
     }
 
     #[test]
@@ -119,8 +274,11 @@ mod tests {
             content: None,
             pub_date: None,
             author: None,
-            category: None,
+            categories: Vec::new(),
             guid: None,
+            enclosure: None,
+            image: None,
+            extra: Default::default(),
         };
 
         let item = RssGenerator::article_to_item(&article);
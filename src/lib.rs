@@ -5,6 +5,7 @@ pub mod fetcher;
 pub mod parser;
 pub mod generator;
 pub mod plugins;
+pub mod store;
 
 pub use error::{RssHubError, Result};
 pub use config::types::*;
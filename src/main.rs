@@ -1,9 +1,9 @@
 use rust_rsshub::router;
 use rust_rsshub::plugins::PluginRegistry;
+use rust_rsshub::store::Store;
 use std::sync::Arc;
 use tokio::net::TcpListener;
-use tracing::info;
-use tracing_subscriber;
+use tracing::{info, warn};
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
@@ -22,7 +22,27 @@ async fn main() -> anyhow::Result<()> {
     info!("Loading plugins from: {}", configs_dir);
 
     // 创建插件注册表
-    let registry = Arc::new(PluginRegistry::new(configs_dir)?);
+    let registry = match std::env::var("PARSE_CONCURRENCY") {
+        Ok(value) => PluginRegistry::with_parse_concurrency(configs_dir, value.parse::<usize>()?)?,
+        Err(_) => PluginRegistry::new(configs_dir)?,
+    };
+
+    // 打开条目历史存储
+    let data_dir = std::env::var("DATA_DIR")
+        .unwrap_or_else(|_| "data".to_string());
+
+    // 存储只有 history、monitor 和 first_seen 用到，打不开时其余插件照常工作
+    let registry = match Store::open(&data_dir) {
+        Ok(store) => {
+            info!("Storing item history in: {}", data_dir);
+            registry.with_store(Arc::new(store))
+        }
+        Err(e) => {
+            warn!("Failed to open data store at {}: {}; running without item history", data_dir, e);
+            registry
+        }
+    };
+    let registry = Arc::new(registry);
 
     // 创建路由
     let app = router::create_router(registry);
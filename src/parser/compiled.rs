@@ -0,0 +1,819 @@
+use crate::config::types::{
+    Article, Enclosure, Enrichment, FieldSelector, GuidConfig, ListParserConfig, ParseBudget, ParserConfig,
+    ParserMode, PluginConfig, SourceConfig,
+};
+use crate::error::{Result, RssHubError};
+use crate::parser::html::HtmlParser;
+use crate::parser::content::CompiledContent;
+use crate::parser::embedded::CompiledEmbedded;
+use crate::parser::guid::CompiledGuid;
+use crate::parser::json::JsonList;
+use crate::parser::jsonld;
+use crate::parser::media::guess_mime_type;
+use crate::parser::meta;
+use crate::parser::microformats::MicroformatsParser;
+use crate::parser::monitor::CompiledMonitor;
+use crate::parser::field::{html_description, FieldRules};
+use crate::parser::sanitize::sanitize_html;
+use crate::parser::url_date::UrlDatePattern;
+use chrono::{DateTime, Utc};
+use scraper::{ElementRef, Html, Selector};
+use std::collections::{BTreeMap, HashSet};
+use std::time::{Duration, Instant};
+
+/// 预编译的解析器
+///
+/// 在加载插件配置时构建一次，持有所有 `Selector` 和正则，
+/// 之后每次请求解析时不再编译任何选择器。
+#[derive(Debug, Clone)]
+pub struct CompiledParser {
+    mode: CompiledMode,
+    guid: Option<CompiledGuid>,
+    content: Option<CompiledContent>,
+    enrich: Vec<Enrichment>,
+    url_date: Option<UrlDatePattern>,
+    budget: ParseBudget,
+}
+
+/// 预编译的解析模式
+#[derive(Debug, Clone)]
+enum CompiledMode {
+    /// 按 `list` 或 `lists` 中的规则依次提取，结果按顺序拼接
+    Css(Vec<CompiledList>),
+    Jsonld,
+    Microformats(Box<MicroformatsParser>),
+    /// 按 JSON 路径解析；设置了 `embedded` 时先从 HTML 页面的脚本中取出 JSON
+    Json {
+        lists: Vec<JsonList>,
+        embedded: Option<CompiledEmbedded>,
+    },
+    /// 页面监控，只生成快照，不解析文章
+    Monitor(Box<CompiledMonitor>),
+}
+
+/// 预编译的列表页解析规则
+#[derive(Debug, Clone)]
+struct CompiledList {
+    selector: Selector,
+    item_selector: Option<Selector>,
+    title: CompiledField,
+    link: CompiledField,
+    description: Option<CompiledField>,
+    date: Option<CompiledField>,
+    date_format: Option<String>,
+    author: Option<CompiledField>,
+    category: Option<CompiledField>,
+    category_delimiter: Option<String>,
+    enclosure: Option<CompiledField>,
+    enclosure_type: Option<String>,
+    enclosure_type_attr: String,
+    enclosure_length_attr: String,
+    image: Option<CompiledField>,
+    extra: Vec<(String, CompiledField)>,
+}
+
+/// 预编译的字段选择器
+#[derive(Debug, Clone)]
+pub struct CompiledField {
+    selectors: Vec<Selector>,
+    attr: Option<String>,
+    rules: FieldRules,
+}
+
+/// 没有配置 `content`、只配置 `enrich: [meta]` 或 `[canonical]` 时同时抓取的详情页数量
+const DEFAULT_DETAIL_CONCURRENCY: usize = 4;
+
+/// 媒体元素上默认依次尝试的 URL 属性
+const MEDIA_URL_ATTRS: [&str; 2] = ["src", "href"];
+
+impl CompiledParser {
+    /// 编译解析器配置，无效的选择器或正则会在这里报错
+    pub fn compile(config: &ParserConfig) -> Result<Self> {
+        let mode = match config.mode {
+            ParserMode::Css => CompiledMode::Css(compile_lists(config, CompiledList::compile)?),
+            ParserMode::Jsonld => CompiledMode::Jsonld,
+            ParserMode::Microformats => CompiledMode::Microformats(Box::default()),
+            ParserMode::Monitor => {
+                let monitor = config
+                    .monitor
+                    .as_ref()
+                    .ok_or_else(|| RssHubError::Config("parser.monitor is required in monitor mode".into()))?;
+                CompiledMode::Monitor(Box::new(CompiledMonitor::compile(monitor)?))
+            }
+        };
+
+        Self::with_mode(config, mode)
+    }
+
+    /// 按插件的数据源编译解析器
+    ///
+    /// JSON 数据源和设置了 `embedded` 的 HTML 数据源使用 JSON 路径解析，其余同 `compile`
+    pub fn for_plugin(config: &PluginConfig) -> Result<Self> {
+        let embedded = match config.source {
+            SourceConfig::Json(_) => None,
+            SourceConfig::Html(ref html) if html.embedded.is_some() => html.embedded.as_ref(),
+            _ => return Self::compile(&config.parser),
+        };
+
+        let parser = &config.parser;
+        if parser.mode != ParserMode::Css {
+            return Err(RssHubError::Config(
+                "parser.mode is not supported for json sources and embedded data".into(),
+            ));
+        }
+
+        let mode = CompiledMode::Json {
+            lists: compile_lists(parser, |list| JsonList::compile(list, parser.guid.as_ref()))?,
+            embedded: embedded.map(CompiledEmbedded::compile).transpose()?,
+        };
+
+        Self::with_mode(parser, mode)
+    }
+
+    fn with_mode(config: &ParserConfig, mode: CompiledMode) -> Result<Self> {
+        // JSON 模式下选择器策略按 JSON 路径提取，由 `JsonList` 处理
+        let guid = match (&mode, &config.guid) {
+            (CompiledMode::Json { .. }, Some(GuidConfig::Selector { .. })) => None,
+            (_, guid) => guid.as_ref().map(CompiledGuid::compile).transpose()?,
+        };
+
+        Ok(Self {
+            mode,
+            guid,
+            content: config.content.as_ref().map(CompiledContent::compile).transpose()?,
+            enrich: config.enrich.clone(),
+            url_date: config
+                .date_fallback
+                .url_pattern
+                .as_deref()
+                .map(UrlDatePattern::compile)
+                .transpose()?,
+            budget: config.budget.clone(),
+        })
+    }
+
+    /// 解析文档
+    ///
+    /// 文档大小或耗时超出解析预算时返回 `ParseError`
+    pub fn parse(&self, html: &str, base_url: &str, limit: usize) -> Result<Vec<Article>> {
+        self.check_size(html)?;
+
+        let deadline = Deadline::after(Duration::from_millis(self.budget.max_duration_ms));
+
+        let mut articles = match self.mode {
+            CompiledMode::Css(ref lists) => {
+                let document = Html::parse_document(html);
+                let mut articles = Vec::new();
+                for list in lists {
+                    let remaining = limit - articles.len();
+                    if remaining == 0 {
+                        break;
+                    }
+                    articles.extend(self.parse_list(list, &document, base_url, remaining, &deadline)?);
+                }
+                if self.enrich.contains(&Enrichment::Jsonld) {
+                    jsonld::enrich_articles(&mut articles, &document, base_url, &deadline)?;
+                }
+                articles
+            }
+            CompiledMode::Jsonld => {
+                let document = Html::parse_document(html);
+                let mut articles = jsonld::extract_articles(&document, base_url, limit, &deadline)?;
+                self.apply_guid(&mut articles);
+                articles
+            }
+            CompiledMode::Microformats(ref microformats) => {
+                let document = Html::parse_document(html);
+                let mut articles = microformats.extract_articles(&document, base_url, limit, &deadline)?;
+                self.apply_guid(&mut articles);
+                articles
+            }
+            CompiledMode::Json { ref lists, ref embedded } => {
+                let root = match embedded {
+                    Some(embedded) => embedded.extract(&Html::parse_document(html))?,
+                    None => serde_json::from_str(html)
+                        .map_err(|e| RssHubError::ParseError(format!("Invalid JSON: {}", e)))?,
+                };
+                deadline.check()?;
+
+                let mut articles = Vec::new();
+                'lists: for list in lists {
+                    for item in list.items(&root) {
+                        // 限制数量
+                        if articles.len() >= limit {
+                            break 'lists;
+                        }
+                        deadline.check()?;
+
+                        if let Some(article) = list.parse_article(item, base_url) {
+                            articles.push(article);
+                        }
+                    }
+                }
+                self.apply_guid(&mut articles);
+                articles
+            }
+            CompiledMode::Monitor(_) => {
+                return Err(RssHubError::ParseError(
+                    "Monitor mode produces snapshots, not articles".into(),
+                ))
+            }
+        };
+
+        deadline.check()?;
+
+        // 抓取详情页时 `<meta>` 中的日期优先，链接中的日期等详情页解析之后再使用
+        if !self.enrich.contains(&Enrichment::Meta) {
+            self.fill_url_dates(&mut articles);
+        }
+
+        Ok(articles)
+    }
+
+    /// 没有日期的条目按 `date_fallback.url_pattern` 从链接推断
+    ///
+    /// 配置了 `enrich: [meta]` 时 `parse` 不会调用，需要在 `parse_detail` 之后调用
+    pub fn fill_url_dates(&self, articles: &mut [Article]) {
+        for article in articles {
+            if article.pub_date.is_none() {
+                article.pub_date = self.date_from_url(article);
+            }
+        }
+    }
+
+    /// 按 `guid` 的链接或哈希策略重新生成 GUID
+    ///
+    /// 解析时已经生成过一次；清理链接、抓取详情页和补全日期后字段可能变化，
+    /// 插件在去重之前再调用一次。选择器策略需要 HTML 元素，保留解析时的结果。
+    pub fn apply_guids(&self, articles: &mut [Article]) {
+        if self.regenerates_guids() {
+            self.apply_guid(articles);
+        }
+    }
+
+    /// 是否有需要在后处理中重新生成的 GUID 策略
+    pub fn regenerates_guids(&self) -> bool {
+        self.guid.as_ref().is_some_and(CompiledGuid::uses_fields)
+    }
+
+    /// 是否为页面监控模式
+    pub fn is_monitor(&self) -> bool {
+        matches!(self.mode, CompiledMode::Monitor(_))
+    }
+
+    /// 监控模式下被监控区域的文本快照，选择器没有匹配到内容时返回 `ParseError`
+    pub fn snapshot(&self, html: &str) -> Result<String> {
+        self.check_size(html)?;
+
+        match self.mode {
+            CompiledMode::Monitor(ref monitor) => monitor
+                .snapshot(html)
+                .ok_or_else(|| RssHubError::ParseError("Monitored selector matched nothing".into())),
+            _ => Err(RssHubError::ParseError("Snapshots are only available in monitor mode".into())),
+        }
+    }
+
+    /// 是否需要抓取文章详情页（配置了 `content` 或 `enrich: [meta]`、`[canonical]`）
+    pub fn fetches_detail(&self) -> bool {
+        self.content.is_some()
+            || self.enrich.contains(&Enrichment::Meta)
+            || self.enrich.contains(&Enrichment::Canonical)
+    }
+
+    /// 同时抓取的详情页数量
+    pub fn detail_concurrency(&self) -> usize {
+        self.content.as_ref().map_or(DEFAULT_DETAIL_CONCURRENCY, CompiledContent::concurrency)
+    }
+
+    /// 解析文章详情页
+    ///
+    /// 提取到的正文覆盖列表页中的内容；`enrich: [meta]` 时用页面的 `<meta>` 标签补全空字段，
+    /// `enrich: [canonical]` 时用页面声明的规范链接替换文章链接。
+    /// 文档大小超出解析预算时返回 `ParseError`
+    pub fn parse_detail(&self, html: &str, base_url: &str, article: &mut Article) -> Result<()> {
+        if !self.fetches_detail() {
+            return Ok(());
+        }
+
+        self.check_size(html)?;
+        let document = Html::parse_document(html);
+
+        if self.enrich.contains(&Enrichment::Meta) {
+            jsonld::fill_missing(article, &meta::extract_meta(&document, base_url));
+        }
+
+        if self.enrich.contains(&Enrichment::Canonical) {
+            if let Some(link) = meta::extract_canonical(&document, base_url) {
+                article.link = link;
+            }
+        }
+
+        if let Some(ref content) = self.content {
+            if let Some(html) = content.extract(document, base_url) {
+                article.content = Some(html);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// 按 `date_fallback.url_pattern` 从文章链接中推断的日期
+    fn date_from_url(&self, article: &Article) -> Option<DateTime<Utc>> {
+        self.url_date.as_ref().and_then(|pattern| pattern.extract(&article.link))
+    }
+
+    fn check_size(&self, html: &str) -> Result<()> {
+        if html.len() > self.budget.max_bytes {
+            return Err(RssHubError::ParseError(format!(
+                "Parse budget exceeded: document is {} bytes (limit {} bytes)",
+                html.len(),
+                self.budget.max_bytes
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// 按 CSS 规则逐条提取文章
+    fn parse_list(
+        &self,
+        list: &CompiledList,
+        document: &Html,
+        base_url: &str,
+        limit: usize,
+        deadline: &Deadline,
+    ) -> Result<Vec<Article>> {
+        let mut articles = Vec::new();
+
+        for element in list.items(document) {
+            deadline.check()?;
+
+            if let Some(mut article) = list.parse_article(&element, base_url) {
+                if let Some(ref guid) = self.guid {
+                    article.guid = guid.generate(&article, Some(&element));
+                }
+                articles.push(article);
+            }
+
+            // 限制数量
+            if articles.len() >= limit {
+                break;
+            }
+        }
+
+        Ok(articles)
+    }
+
+    /// 为没有对应 HTML 元素的文章生成 GUID
+    fn apply_guid(&self, articles: &mut [Article]) {
+        if let Some(ref guid) = self.guid {
+            for article in articles {
+                article.guid = guid.generate(article, None);
+            }
+        }
+    }
+
+}
+
+/// 单次解析的截止时间
+///
+/// 逐条提取文章的循环中调用 `check`，超时后不再继续解析
+#[derive(Debug, Clone, Copy)]
+pub struct Deadline {
+    at: Instant,
+    budget: Duration,
+}
+
+impl Deadline {
+    pub fn after(budget: Duration) -> Self {
+        Self {
+            at: Instant::now() + budget,
+            budget,
+        }
+    }
+
+    /// 超过截止时间时返回 `ParseError`
+    pub fn check(&self) -> Result<()> {
+        if Instant::now() > self.at {
+            return Err(RssHubError::ParseError(format!(
+                "Parse budget exceeded: took longer than {} ms",
+                self.budget.as_millis()
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+impl CompiledList {
+    /// 文章条目：`selector` 选出容器，设置了 `item_selector` 时再从每个容器中选出条目，
+    /// 否则容器本身就是条目。嵌套容器中重复选中的条目只保留一次。
+    fn items<'a>(&'a self, document: &'a Html) -> Vec<ElementRef<'a>> {
+        let containers = document.select(&self.selector);
+
+        let Some(ref item_selector) = self.item_selector else {
+            return containers.collect();
+        };
+
+        let mut seen = HashSet::new();
+        containers
+            .flat_map(|container| container.select(item_selector))
+            .filter(|item| seen.insert(item.id()))
+            .collect()
+    }
+
+    fn compile(config: &ListParserConfig) -> Result<Self> {
+        Ok(Self {
+            selector: compile_selector("selector", &config.selector)?,
+            item_selector: config
+                .item_selector
+                .as_deref()
+                .map(|selector| compile_selector("item_selector", selector))
+                .transpose()?,
+            title: CompiledField::compile("title_selector", &config.title_selector)?,
+            link: CompiledField::compile("link_selector", &config.link_selector)?,
+            description: CompiledField::compile_optional(
+                "description_selector",
+                config.description_selector.as_ref(),
+            )?,
+            date: CompiledField::compile_optional("date_selector", config.date_selector.as_ref())?,
+            date_format: config.date_format.clone(),
+            author: CompiledField::compile_optional("author_selector", config.author_selector.as_ref())?,
+            category: CompiledField::compile_optional(
+                "category_selector",
+                config.category_selector.as_ref(),
+            )?,
+            category_delimiter: config.category_delimiter.clone(),
+            enclosure: CompiledField::compile_optional(
+                "enclosure_selector",
+                config.enclosure_selector.as_ref(),
+            )?,
+            enclosure_type: config.enclosure_type.clone(),
+            enclosure_type_attr: config
+                .enclosure_type_attr
+                .clone()
+                .unwrap_or_else(|| "type".to_string()),
+            enclosure_length_attr: config
+                .enclosure_length_attr
+                .clone()
+                .unwrap_or_else(|| "length".to_string()),
+            image: CompiledField::compile_optional("image_selector", config.image_selector.as_ref())?,
+            extra: config
+                .extra_fields
+                .iter()
+                .map(|(name, field)| {
+                    check_extra_field_name(name)?;
+                    Ok((name.clone(), CompiledField::compile(&format!("extra_fields.{}", name), field)?))
+                })
+                .collect::<Result<_>>()?,
+        })
+    }
+
+    fn parse_article(&self, element: &ElementRef, base_url: &str) -> Option<Article> {
+        // 提取标题，如果标题为空，跳过这篇文章
+        let title = self.title.extract_text(element)?;
+
+        // 提取链接，如果链接提取失败，跳过
+        let link = self.link.extract_link(element, base_url)?;
+
+        // 提取描述；html 模式下保留清理后的标记作为内容，描述使用其纯文本
+        let (description, content) = match self.description {
+            Some(ref field) if field.rules.html => field
+                .rules
+                .require(field.extract_html(element, base_url))?
+                .map_or((None, None), html_description),
+            _ => (CompiledField::extract_optional(self.description.as_ref(), element)?, None),
+        };
+
+        // 提取日期
+        let pub_date = match self.date {
+            Some(ref field) => field.rules.require(
+                field
+                    .extract_text(element)
+                    .and_then(|text| HtmlParser::parse_date(&text, self.date_format.as_deref())),
+            )?,
+            None => None,
+        };
+
+        // 提取作者
+        let author = CompiledField::extract_optional(self.author.as_ref(), element)?;
+
+        // 提取分类
+        let categories = match self.category {
+            Some(ref field) => {
+                let categories = field.extract_all(element, self.category_delimiter.as_deref());
+                field.rules.require(Some(categories).filter(|c| !c.is_empty()))?.unwrap_or_default()
+            }
+            None => Vec::new(),
+        };
+
+        // 提取附件
+        let enclosure = match self.enclosure {
+            Some(ref field) => field.rules.require(self.extract_enclosure(field, element, base_url))?,
+            None => None,
+        };
+
+        // 提取配图
+        let image = match self.image {
+            Some(ref field) => field.rules.require(field.extract_media(element, base_url).map(|(_, url)| url))?,
+            None => None,
+        };
+
+        // 提取自定义字段
+        let mut extra = BTreeMap::new();
+        for (name, field) in &self.extra {
+            if let Some(value) = CompiledField::extract_optional(Some(field), element)? {
+                extra.insert(name.clone(), value);
+            }
+        }
+
+        Some(Article {
+            title,
+            link,
+            description,
+            content,
+            pub_date,
+            author,
+            categories,
+            guid: None,
+            enclosure,
+            image,
+            extra,
+        })
+    }
+
+    /// 附件的 MIME 类型依次取自固定配置、元素属性和 URL 扩展名
+    fn extract_enclosure(
+        &self,
+        field: &CompiledField,
+        element: &ElementRef,
+        base_url: &str,
+    ) -> Option<Enclosure> {
+        let (media, url) = field.extract_media(element, base_url)?;
+
+        let attr = |name: &str| {
+            media
+                .as_ref()
+                .and_then(|el| el.value().attr(name))
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+        };
+
+        let mime_type = self
+            .enclosure_type
+            .clone()
+            .or_else(|| attr(&self.enclosure_type_attr).map(str::to_string))
+            .unwrap_or_else(|| guess_mime_type(&url).to_string());
+        let length = attr(&self.enclosure_length_attr).and_then(|len| len.parse().ok());
+
+        Some(Enclosure {
+            url,
+            mime_type,
+            length,
+        })
+    }
+}
+
+impl CompiledField {
+    pub fn compile(name: &str, field: &FieldSelector) -> Result<Self> {
+        let selectors = field
+            .selectors
+            .iter()
+            .map(|selector| compile_selector(name, selector))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            selectors,
+            attr: field.attr.clone(),
+            rules: FieldRules::compile(name, field)?,
+        })
+    }
+
+    fn compile_optional(name: &str, field: Option<&FieldSelector>) -> Result<Option<Self>> {
+        field.map(|field| Self::compile(name, field)).transpose()
+    }
+
+    /// 按顺序尝试选择器，对第一个非空匹配执行转换；结果为空时使用默认值
+    pub fn extract_text(&self, element: &ElementRef) -> Option<String> {
+        let text = self
+            .selectors
+            .iter()
+            .filter_map(|sel| element.select(sel).next())
+            .map(|el| self.element_value(el))
+            .find(|text| !text.is_empty());
+
+        self.rules.finish(text)
+    }
+
+    /// 返回第一个有匹配的选择器的所有非空匹配，可按分隔符拆分，结果去重
+    ///
+    /// 没有任何匹配时使用默认值
+    pub fn extract_all(&self, element: &ElementRef, delimiter: Option<&str>) -> Vec<String> {
+        let groups = self
+            .selectors
+            .iter()
+            .map(|sel| element.select(sel).map(|el| self.element_value(el)));
+
+        self.rules.collect_all(groups, delimiter)
+    }
+
+    /// 按顺序尝试选择器，返回第一个非空匹配的内部 HTML，经过转换和清理
+    pub fn extract_html(&self, element: &ElementRef, base_url: &str) -> Option<String> {
+        let html = self
+            .selectors
+            .iter()
+            .filter_map(|sel| element.select(sel).next())
+            .map(|el| el.inner_html())
+            .find(|html| !html.trim().is_empty());
+
+        self.rules
+            .finish(html)
+            .map(|html| sanitize_html(&html, base_url))
+            .filter(|html| !html.is_empty())
+    }
+
+    /// 按顺序尝试链接选择器，返回第一个带 href（或 `attr` 指定属性）的匹配
+    pub fn extract_link(&self, element: &ElementRef, base_url: &str) -> Option<String> {
+        let attr = self.attr.as_deref().unwrap_or("href");
+        let href = self
+            .selectors
+            .iter()
+            .find_map(|sel| element.select(sel).find_map(|el| el.value().attr(attr)))
+            .map(|href| href.to_string());
+
+        self.rules
+            .finish(href)
+            .map(|href| HtmlParser::resolve_url(&href, base_url))
+    }
+
+    /// 按顺序尝试媒体选择器，返回第一个带 URL 属性的元素及其绝对 URL
+    ///
+    /// 使用默认值时没有对应元素
+    pub fn extract_media<'a>(
+        &self,
+        element: &ElementRef<'a>,
+        base_url: &str,
+    ) -> Option<(Option<ElementRef<'a>>, String)> {
+        let found = self.selectors.iter().find_map(|sel| {
+            element.select(sel).find_map(|el| {
+                let url = match self.attr {
+                    Some(ref attr) => el.value().attr(attr),
+                    None => MEDIA_URL_ATTRS.iter().find_map(|attr| el.value().attr(attr)),
+                }?;
+                Some((el, url.trim().to_string())).filter(|(_, url)| !url.is_empty())
+            })
+        });
+
+        let (media, url) = match found {
+            Some((el, url)) => (Some(el), Some(url)),
+            None => (None, None),
+        };
+
+        self.rules
+            .finish(url)
+            .map(|url| (media, HtmlParser::resolve_url(&url, base_url)))
+    }
+
+    /// 提取可选字段
+    ///
+    /// 返回 `None` 表示 required 字段缺失，调用方应跳过该条目
+    fn extract_optional(field: Option<&Self>, element: &ElementRef) -> Option<Option<String>> {
+        field.map_or(Some(None), |field| field.rules.require(field.extract_text(element)))
+    }
+
+    /// 元素的取值：设置了 `attr` 时为该属性，否则为文本内容
+    fn element_value(&self, element: ElementRef) -> String {
+        match self.attr {
+            Some(ref attr) => element.value().attr(attr).unwrap_or_default().trim().to_string(),
+            None => element_text(element),
+        }
+    }
+}
+
+/// 按顺序编译 `list` 或 `lists` 中的每个规则
+///
+/// 使用 `lists` 时，配置错误会带上规则的位置，如 `parser.lists[1]: ...`
+pub(crate) fn compile_lists<T>(
+    config: &ParserConfig,
+    compile: impl Fn(&ListParserConfig) -> Result<T>,
+) -> Result<Vec<T>> {
+    if config.lists.is_empty() {
+        return Ok(vec![compile(&config.list)?]);
+    }
+    if !config.list.selector.is_empty() {
+        return Err(RssHubError::Config(
+            "parser.list and parser.lists cannot be used together".into(),
+        ));
+    }
+
+    config
+        .lists
+        .iter()
+        .enumerate()
+        .map(|(index, list)| {
+            compile(list).map_err(|e| match e {
+                RssHubError::Config(message) => {
+                    RssHubError::Config(format!("parser.lists[{}]: {}", index, message))
+                }
+                e => e,
+            })
+        })
+        .collect()
+}
+
+/// 自定义字段名会作为 XML 元素名输出，只允许字母、数字、`_` 和 `-`，且不能以数字或 `-` 开头
+pub(crate) fn check_extra_field_name(name: &str) -> Result<()> {
+    let mut chars = name.chars();
+    let valid = chars.next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+
+    if valid {
+        Ok(())
+    } else {
+        Err(RssHubError::Config(format!(
+            "Invalid extra field name '{}': use letters, digits, '_' and '-'",
+            name
+        )))
+    }
+}
+
+pub(crate) fn compile_selector(name: &str, selector: &str) -> Result<Selector> {
+    Selector::parse(selector).map_err(|e| {
+        RssHubError::Config(format!("Invalid selector '{}' in {}: {}", selector, name, e))
+    })
+}
+
+/// 元素的文本内容
+fn element_text(element: ElementRef) -> String {
+    element.text().collect::<Vec<_>>().join("").trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn first_div(document: &Html) -> ElementRef<'_> {
+        document.select(&Selector::parse("div").unwrap()).next().unwrap()
+    }
+
+    #[test]
+    fn test_extract_text() {
+        let html = r#"
+        <div>
+            <span class="title">Test Title</span>
+        </div>
+        "#;
+
+        let document = Html::parse_document(html);
+        let field = CompiledField::compile("title_selector", &".title".into()).unwrap();
+
+        let text = field.extract_text(&first_div(&document));
+        assert_eq!(text, Some("Test Title".to_string()));
+    }
+
+    #[test]
+    fn test_extract_link() {
+        let html = r#"
+        <div>
+            <a href="https://example.com/article">Link</a>
+        </div>
+        "#;
+
+        let document = Html::parse_document(html);
+        let field = CompiledField::compile("link_selector", &"a".into()).unwrap();
+
+        let link = field.extract_link(&first_div(&document), "https://base.com");
+        assert_eq!(link, Some("https://example.com/article".to_string()));
+    }
+
+    #[test]
+    fn test_extract_relative_link() {
+        let html = r#"
+        <div>
+            <a href="/article/123">Link</a>
+        </div>
+        "#;
+
+        let document = Html::parse_document(html);
+        let field = CompiledField::compile("link_selector", &"a".into()).unwrap();
+
+        let link = field.extract_link(&first_div(&document), "https://base.com");
+        assert_eq!(link, Some("https://base.com/article/123".to_string()));
+    }
+
+    #[test]
+    fn test_compile_invalid_selector() {
+        let config = ParserConfig {
+            list: ListParserConfig {
+                selector: "div[".to_string(),
+                link_selector: "a".into(),
+                title_selector: "a".into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let result = CompiledParser::compile(&config);
+        assert!(result.is_err());
+    }
+}
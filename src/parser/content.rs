@@ -0,0 +1,111 @@
+use crate::config::types::{ContentMode, ContentParserConfig};
+use crate::error::{Result, RssHubError};
+use crate::parser::compiled::compile_selector;
+use crate::parser::readability::Readability;
+use crate::parser::sanitize::{html_to_text, remove_elements, sanitize_html};
+use scraper::{Html, Selector};
+
+/// 预编译的详情页正文提取规则
+#[derive(Debug, Clone)]
+pub struct CompiledContent {
+    mode: ContentMode,
+    selector: Option<Selector>,
+    content_selector: Option<Selector>,
+    cleanup: Vec<Selector>,
+    readability: Readability,
+    concurrency: usize,
+}
+
+impl CompiledContent {
+    pub fn compile(config: &ContentParserConfig) -> Result<Self> {
+        let optional = |name: &str, selector: &str| {
+            Some(selector.trim())
+                .filter(|selector| !selector.is_empty())
+                .map(|selector| compile_selector(name, selector))
+                .transpose()
+        };
+
+        let content_selector = optional("content.content_selector", &config.content_selector)?;
+        if config.mode == ContentMode::Selector && content_selector.is_none() {
+            return Err(RssHubError::Config(
+                "content.content_selector is required unless content.mode is auto".into(),
+            ));
+        }
+
+        Ok(Self {
+            mode: config.mode,
+            selector: optional("content.selector", &config.selector)?,
+            content_selector,
+            cleanup: config
+                .cleanup_selectors
+                .iter()
+                .map(|selector| compile_selector("content.cleanup_selectors", selector))
+                .collect::<Result<_>>()?,
+            readability: Readability::new(),
+            concurrency: config.concurrency.max(1),
+        })
+    }
+
+    /// 同时抓取的详情页数量
+    pub fn concurrency(&self) -> usize {
+        self.concurrency
+    }
+
+    /// 提取详情页正文，返回清理后的 HTML
+    ///
+    /// `selector` 模式下选择器匹配不到或正文为空时回退到自动提取
+    pub fn extract(&self, mut document: Html, base_url: &str) -> Option<String> {
+        remove_elements(&mut document, &self.cleanup);
+
+        if self.mode == ContentMode::Selector {
+            if let Some(content) = self.select(&document, base_url) {
+                return Some(content);
+            }
+        }
+
+        self.readability.extract(&document, base_url)
+    }
+
+    fn select(&self, document: &Html, base_url: &str) -> Option<String> {
+        let content_selector = self.content_selector.as_ref()?;
+
+        let element = match self.selector {
+            Some(ref selector) => document.select(selector).next()?.select(content_selector).next()?,
+            None => document.select(content_selector).next()?,
+        };
+
+        Some(sanitize_html(&element.inner_html(), base_url)).filter(|html| !html_to_text(html).is_empty())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn compile(yaml: &str) -> CompiledContent {
+        CompiledContent::compile(&serde_yaml::from_str(yaml).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_selector_with_cleanup() {
+        let content = compile(
+            r#"
+            selector: "article"
+            content_selector: ".body"
+            cleanup_selectors: [".ads"]
+            "#,
+        );
+        let html = r#"<article><div class="body"><p>Text</p><div class="ads">Buy</div></div></article>"#;
+
+        let extracted = content.extract(Html::parse_document(html), "https://example.com");
+
+        assert_eq!(extracted.as_deref(), Some("<p>Text</p>"));
+    }
+
+    #[test]
+    fn test_selector_mode_requires_content_selector() {
+        let config: ContentParserConfig = serde_yaml::from_str("selector: article").unwrap();
+
+        assert!(CompiledContent::compile(&config).is_err());
+    }
+}
@@ -0,0 +1,140 @@
+use crate::config::types::EmbeddedConfig;
+use crate::error::{Result, RssHubError};
+use crate::parser::compiled::compile_selector;
+use regex::Regex;
+use scraper::{Html, Selector};
+use serde_json::Value;
+
+/// 预编译的内嵌数据提取规则
+///
+/// 用于 Next.js（`<script id="__NEXT_DATA__">`）、Nuxt 以及
+/// `window.__INITIAL_STATE__ = {...}` 这类把页面数据放在脚本中的单页应用。
+#[derive(Debug, Clone)]
+pub struct CompiledEmbedded {
+    selector: Selector,
+    variable: Option<Regex>,
+}
+
+impl CompiledEmbedded {
+    pub fn compile(config: &EmbeddedConfig) -> Result<Self> {
+        if config.selector.is_none() && config.variable.is_none() {
+            return Err(RssHubError::Config(
+                "embedded requires a selector or a variable".into(),
+            ));
+        }
+
+        let selector_text = config.selector.as_deref().unwrap_or("script");
+        let selector = compile_selector("embedded.selector", selector_text)?;
+
+        // 匹配 `window.NAME =`、`var NAME =`、`NAME=` 等赋值
+        let variable = config
+            .variable
+            .as_deref()
+            .map(|name| {
+                let name = name.trim().trim_start_matches("window.");
+                Regex::new(&format!(r"(?:^|[^\w$.]|window\.){}\s*=\s*", regex::escape(name)))
+                    .map_err(|e| RssHubError::Config(format!("Invalid embedded variable '{}': {}", name, e)))
+            })
+            .transpose()?;
+
+        Ok(Self { selector, variable })
+    }
+
+    /// 从页面中提取 JSON 数据
+    ///
+    /// 只设置 `selector` 时，脚本的全部内容就是 JSON；设置了 `variable` 时，
+    /// 在匹配的脚本中查找该变量的赋值，支持对象字面量和 `JSON.parse("...")`。
+    pub fn extract(&self, document: &Html) -> Result<Value> {
+        for script in document.select(&self.selector) {
+            let text = script.text().collect::<String>();
+
+            let value = match self.variable {
+                Some(ref variable) => variable
+                    .find(&text)
+                    .and_then(|m| parse_assigned_value(&text[m.end()..])),
+                None => serde_json::from_str(text.trim()).ok(),
+            };
+
+            if let Some(value) = value {
+                return Ok(value);
+            }
+        }
+
+        Err(RssHubError::ParseError("Embedded JSON data not found".into()))
+    }
+}
+
+/// 解析赋值号之后的第一个 JSON 值，忽略其后的 `;` 和其他脚本
+fn parse_assigned_value(text: &str) -> Option<Value> {
+    let text = text.trim_start();
+
+    // window.__STATE__ = JSON.parse("{\"posts\":[...]}")
+    if let Some(inner) = text.strip_prefix("JSON.parse(") {
+        let literal: String = first_json_value(inner.trim_start())?;
+        return serde_json::from_str(&literal).ok();
+    }
+
+    first_json_value(text)
+}
+
+fn first_json_value<T: serde::de::DeserializeOwned>(text: &str) -> Option<T> {
+    serde_json::Deserializer::from_str(text)
+        .into_iter::<T>()
+        .next()?
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn extract(config: EmbeddedConfig, html: &str) -> Result<Value> {
+        CompiledEmbedded::compile(&config)?.extract(&Html::parse_document(html))
+    }
+
+    #[test]
+    fn test_extract_by_selector() {
+        let html = r#"<script id="__NEXT_DATA__" type="application/json">{"props":{"posts":[1,2]}}</script>"#;
+        let config = EmbeddedConfig {
+            selector: Some("script#__NEXT_DATA__".to_string()),
+            variable: None,
+        };
+
+        assert_eq!(extract(config, html).unwrap()["props"]["posts"][1], 2);
+    }
+
+    #[test]
+    fn test_extract_by_variable() {
+        let html = r#"
+            <script>var other = 1;</script>
+            <script>window.__INITIAL_STATE__ = {"posts": [{"title": "A"}]}; window.foo = 2;</script>
+        "#;
+        let config = EmbeddedConfig {
+            selector: None,
+            variable: Some("__INITIAL_STATE__".to_string()),
+        };
+
+        assert_eq!(extract(config, html).unwrap()["posts"][0]["title"], "A");
+    }
+
+    #[test]
+    fn test_extract_json_parse_literal() {
+        let html = r#"<script>window.__STATE__=JSON.parse("{\"posts\":[{\"title\":\"B\"}]}");</script>"#;
+        let config = EmbeddedConfig {
+            selector: None,
+            variable: Some("window.__STATE__".to_string()),
+        };
+
+        assert_eq!(extract(config, html).unwrap()["posts"][0]["title"], "B");
+    }
+
+    #[test]
+    fn test_extract_not_found() {
+        let config = EmbeddedConfig {
+            selector: None,
+            variable: Some("__MISSING__".to_string()),
+        };
+
+        assert!(extract(config, "<script>var a = 1;</script>").is_err());
+    }
+}
@@ -0,0 +1,131 @@
+use crate::config::types::FieldSelector;
+use crate::error::{Result, RssHubError};
+use crate::parser::sanitize::html_to_text;
+use crate::parser::transform::{apply_transforms, compile_transforms, CompiledTransform};
+use std::collections::HashSet;
+
+/// 字段取值的公共规则
+///
+/// CSS 选择器和 JSON 路径只负责找到原始值，转换、默认值和 `required` 都在这里处理。
+#[derive(Debug, Clone)]
+pub struct FieldRules {
+    pub(crate) required: bool,
+    pub(crate) html: bool,
+    default: Option<String>,
+    transforms: Vec<CompiledTransform>,
+}
+
+impl FieldRules {
+    /// 编译字段的转换步骤，错误信息带上字段名
+    pub(crate) fn compile(name: &str, field: &FieldSelector) -> Result<Self> {
+        let transforms = compile_transforms(&field.transforms).map_err(|e| match e {
+            RssHubError::Config(msg) => RssHubError::Config(format!("{}: {}", name, msg)),
+            other => other,
+        })?;
+
+        Ok(Self {
+            required: field.required,
+            html: field.html,
+            default: field.default.clone(),
+            transforms,
+        })
+    }
+
+    /// 对提取结果执行转换，结果为空时回退到默认值
+    pub(crate) fn finish(&self, value: Option<String>) -> Option<String> {
+        value
+            .map(|value| apply_transforms(&value, &self.transforms))
+            .filter(|value| !value.is_empty())
+            .or_else(|| self.default.clone())
+    }
+
+    /// 返回 `None` 表示 required 字段缺失，调用方应跳过该条目
+    pub(crate) fn require<T>(&self, value: Option<T>) -> Option<Option<T>> {
+        match value {
+            Some(value) => Some(Some(value)),
+            None if self.required => None,
+            None => Some(None),
+        }
+    }
+
+    /// 多值字段：`groups` 按选择器（或路径）顺序给出原始值，使用第一组非空的结果
+    ///
+    /// 每个值执行转换后可按分隔符拆分，结果去重；都没有值时使用默认值
+    pub(crate) fn collect_all<G, I>(&self, groups: G, delimiter: Option<&str>) -> Vec<String>
+    where
+        G: IntoIterator<Item = I>,
+        I: IntoIterator<Item = String>,
+    {
+        let mut values = Vec::new();
+
+        for group in groups {
+            for text in group {
+                let value = apply_transforms(&text, &self.transforms);
+                match delimiter {
+                    Some(delimiter) => values.extend(value.split(delimiter).map(|v| v.trim().to_string())),
+                    None => values.push(value.trim().to_string()),
+                }
+            }
+
+            values.retain(|v| !v.is_empty());
+            if !values.is_empty() {
+                break;
+            }
+        }
+
+        if values.is_empty() {
+            values.extend(self.default.clone());
+        }
+
+        let mut seen = HashSet::new();
+        values.retain(|v| seen.insert(v.clone()));
+        values
+    }
+}
+
+/// html 模式的描述：清理后的标记作为内容，描述使用其纯文本
+pub(crate) fn html_description(html: String) -> (Option<String>, Option<String>) {
+    let text = html_to_text(&html);
+    (Some(text).filter(|t| !t.is_empty()), Some(html).filter(|h| !h.is_empty()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::types::Transform;
+
+    fn rules(yaml: &str) -> FieldRules {
+        let field: FieldSelector = serde_yaml::from_str(yaml).unwrap();
+        FieldRules::compile("field", &field).unwrap()
+    }
+
+    #[test]
+    fn test_finish_and_require() {
+        let field = rules("{ selector: x, default: n/a, transforms: [{ type: regex, pattern: '(\\d+)' }] }");
+        assert_eq!(field.finish(Some("42 points".to_string())).as_deref(), Some("42"));
+        // 转换结果为空时使用默认值
+        assert_eq!(field.finish(Some("none".to_string())).as_deref(), Some("n/a"));
+
+        let required = rules("{ selector: x, required: true }");
+        assert_eq!(required.require(None::<String>), None);
+        assert_eq!(required.require(Some(1)), Some(Some(1)));
+        assert_eq!(rules("x").require(None::<String>), Some(None));
+    }
+
+    #[test]
+    fn test_collect_all() {
+        let field = FieldRules::compile(
+            "category_selector",
+            &FieldSelector {
+                selectors: vec!["x".to_string()],
+                transforms: vec![Transform::Lowercase],
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        // 第一组没有值时使用下一组，拆分后去重
+        let groups = vec![vec![" ".to_string()], vec!["Rust, Async".to_string(), "rust".to_string()]];
+        assert_eq!(field.collect_all(groups, Some(",")), vec!["rust", "async"]);
+    }
+}
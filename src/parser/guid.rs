@@ -0,0 +1,107 @@
+use crate::config::types::{Article, FieldSelector, GuidConfig, GuidField};
+use crate::error::Result;
+use crate::parser::compiled::CompiledField;
+use crate::parser::link::normalize_link;
+use scraper::ElementRef;
+use sha2::{Digest, Sha256};
+
+/// 预编译的 GUID 生成策略
+#[derive(Debug, Clone)]
+pub enum CompiledGuid {
+    Selector(CompiledField),
+    Link { strip_params: Vec<String> },
+    Hash { fields: Vec<GuidField> },
+}
+
+impl CompiledGuid {
+    pub fn compile(config: &GuidConfig) -> Result<Self> {
+        let compiled = match config {
+            GuidConfig::Selector { selector, attr } => {
+                let field = FieldSelector {
+                    selectors: selector.clone(),
+                    attr: attr.clone(),
+                    ..Default::default()
+                };
+                CompiledGuid::Selector(CompiledField::compile("guid", &field)?)
+            }
+            GuidConfig::Link { strip_params } => CompiledGuid::Link {
+                strip_params: strip_params.clone(),
+            },
+            GuidConfig::Hash { fields } => CompiledGuid::Hash {
+                fields: fields.clone(),
+            },
+        };
+
+        Ok(compiled)
+    }
+
+    /// 是否只依赖文章字段（链接或哈希策略），可以在解析之后重新生成
+    pub fn uses_fields(&self) -> bool {
+        !matches!(self, CompiledGuid::Selector(_))
+    }
+
+    /// 为文章生成 GUID，无法生成时返回 `None`（生成器会回退到文章链接）
+    ///
+    /// 选择器策略需要文章对应的 HTML 元素
+    pub fn generate(&self, article: &Article, element: Option<&ElementRef>) -> Option<String> {
+        match self {
+            CompiledGuid::Selector(field) => element.and_then(|element| field.extract_text(element)),
+            CompiledGuid::Link { strip_params } => Some(normalize_link(&article.link, strip_params)),
+            CompiledGuid::Hash { fields } => Some(hash_fields(article, fields)),
+        }
+    }
+}
+
+/// 对所选字段计算 SHA-256，各字段之间以换行分隔，缺失的字段视为空字符串
+fn hash_fields(article: &Article, fields: &[GuidField]) -> String {
+    let mut hasher = Sha256::new();
+
+    for (i, field) in fields.iter().enumerate() {
+        if i > 0 {
+            hasher.update(b"\n");
+        }
+
+        let value = match field {
+            GuidField::Title => Some(article.title.clone()),
+            GuidField::Link => Some(article.link.clone()),
+            GuidField::Description => article.description.clone(),
+            GuidField::Date => article.pub_date.map(|date| date.to_rfc3339()),
+            GuidField::Author => article.author.clone(),
+        };
+        hasher.update(value.unwrap_or_default().as_bytes());
+    }
+
+    format!("{:x}", hasher.finalize())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn article(link: &str) -> Article {
+        Article {
+            title: "Title".to_string(),
+            link: link.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_hash_ignores_unselected_fields() {
+        let fields = vec![GuidField::Title];
+        let a = hash_fields(&article("https://example.com/a?utm_source=x"), &fields);
+        let b = hash_fields(&article("https://example.com/a"), &fields);
+
+        assert_eq!(a, b);
+        assert_eq!(a.len(), 64);
+    }
+
+    #[test]
+    fn test_hash_depends_on_selected_fields() {
+        let fields = vec![GuidField::Title, GuidField::Link];
+        let a = hash_fields(&article("https://example.com/a"), &fields);
+        let b = hash_fields(&article("https://example.com/b"), &fields);
+
+        assert_ne!(a, b);
+    }
+}
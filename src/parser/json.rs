@@ -0,0 +1,364 @@
+use crate::config::types::{Article, Enclosure, FieldSelector, GuidConfig, ListParserConfig};
+use crate::error::{Result, RssHubError};
+use crate::parser::compiled::check_extra_field_name;
+use crate::parser::html::HtmlParser;
+use crate::parser::media::guess_mime_type;
+use crate::parser::field::{html_description, FieldRules};
+use crate::parser::sanitize::sanitize_html;
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// JSON 路径，如 `$.data.posts[*]`、`props.pageProps.posts`、`author.name`、`tags[0]`
+///
+/// 开头的 `$` 可省略；空路径或 `$` 表示当前值本身。
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonPath {
+    segments: Vec<Segment>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Segment {
+    Key(String),
+    Index(usize),
+    Wildcard,
+}
+
+impl JsonPath {
+    pub fn parse(path: &str) -> std::result::Result<Self, String> {
+        let path = path.trim();
+        let mut rest = path.strip_prefix('$').unwrap_or(path);
+        let mut segments = Vec::new();
+
+        while !rest.is_empty() {
+            if let Some(after) = rest.strip_prefix('[') {
+                let end = after.find(']').ok_or("unclosed '['")?;
+                let inner = after[..end].trim();
+                let segment = if inner == "*" {
+                    Segment::Wildcard
+                } else if let Ok(index) = inner.parse() {
+                    Segment::Index(index)
+                } else {
+                    let key = inner
+                        .strip_prefix(['\'', '"'])
+                        .and_then(|key| key.strip_suffix(['\'', '"']))
+                        .ok_or_else(|| format!("invalid index '{}'", inner))?;
+                    Segment::Key(key.to_string())
+                };
+                segments.push(segment);
+                rest = &after[end + 1..];
+            } else {
+                rest = rest.strip_prefix('.').unwrap_or(rest);
+                let end = rest.find(['.', '[']).unwrap_or(rest.len());
+                let key = &rest[..end];
+                if key.is_empty() {
+                    return Err("empty key".to_string());
+                }
+                segments.push(if key == "*" { Segment::Wildcard } else { Segment::Key(key.to_string()) });
+                rest = &rest[end..];
+            }
+        }
+
+        Ok(Self { segments })
+    }
+
+    /// 返回路径匹配的所有值
+    pub fn select<'a>(&self, value: &'a Value) -> Vec<&'a Value> {
+        let mut current = vec![value];
+
+        for segment in &self.segments {
+            current = current
+                .into_iter()
+                .flat_map(|value| -> Vec<&Value> {
+                    match (segment, value) {
+                        (Segment::Key(key), Value::Object(map)) => map.get(key).into_iter().collect(),
+                        (Segment::Index(index), Value::Array(items)) => items.get(*index).into_iter().collect(),
+                        (Segment::Wildcard, Value::Array(items)) => items.iter().collect(),
+                        (Segment::Wildcard, Value::Object(map)) => map.values().collect(),
+                        _ => Vec::new(),
+                    }
+                })
+                .collect();
+        }
+
+        current
+    }
+}
+
+/// 预编译的 JSON 列表解析规则
+///
+/// 复用 `list` 配置：`selector` 是条目数组的路径，各 `*_selector` 是相对条目的路径
+#[derive(Debug, Clone)]
+pub struct JsonList {
+    items: JsonPath,
+    title: JsonField,
+    link: JsonField,
+    description: Option<JsonField>,
+    date: Option<JsonField>,
+    date_format: Option<String>,
+    author: Option<JsonField>,
+    category: Option<JsonField>,
+    category_delimiter: Option<String>,
+    enclosure: Option<JsonField>,
+    enclosure_type: Option<String>,
+    image: Option<JsonField>,
+    guid: Option<JsonField>,
+    extra: Vec<(String, JsonField)>,
+}
+
+/// 预编译的 JSON 字段
+#[derive(Debug, Clone)]
+struct JsonField {
+    paths: Vec<JsonPath>,
+    rules: FieldRules,
+}
+
+impl JsonList {
+    /// 编译 JSON 列表规则，`guid` 为选择器策略时按条目内的路径提取
+    pub fn compile(config: &ListParserConfig, guid: Option<&GuidConfig>) -> Result<Self> {
+        let guid = match guid {
+            Some(GuidConfig::Selector { selector, .. }) => {
+                Some(JsonField::compile("guid", &FieldSelector::from(selector.clone()))?)
+            }
+            _ => None,
+        };
+
+        Ok(Self {
+            items: compile_path("selector", &config.selector)?,
+            title: JsonField::compile("title_selector", &config.title_selector)?,
+            link: JsonField::compile("link_selector", &config.link_selector)?,
+            description: JsonField::compile_optional("description_selector", config.description_selector.as_ref())?,
+            date: JsonField::compile_optional("date_selector", config.date_selector.as_ref())?,
+            date_format: config.date_format.clone(),
+            author: JsonField::compile_optional("author_selector", config.author_selector.as_ref())?,
+            category: JsonField::compile_optional("category_selector", config.category_selector.as_ref())?,
+            category_delimiter: config.category_delimiter.clone(),
+            enclosure: JsonField::compile_optional("enclosure_selector", config.enclosure_selector.as_ref())?,
+            enclosure_type: config.enclosure_type.clone(),
+            image: JsonField::compile_optional("image_selector", config.image_selector.as_ref())?,
+            guid,
+            extra: config
+                .extra_fields
+                .iter()
+                .map(|(name, field)| {
+                    check_extra_field_name(name)?;
+                    Ok((name.clone(), JsonField::compile(&format!("extra_fields.{}", name), field)?))
+                })
+                .collect::<Result<_>>()?,
+        })
+    }
+
+    /// 文章条目：路径匹配到数组时展开为其中的元素
+    pub fn items<'a>(&self, root: &'a Value) -> Vec<&'a Value> {
+        self.items
+            .select(root)
+            .into_iter()
+            .flat_map(|value| match value {
+                Value::Array(items) => items.iter().collect(),
+                other => vec![other],
+            })
+            .collect()
+    }
+
+    pub fn parse_article(&self, item: &Value, base_url: &str) -> Option<Article> {
+        // 标题和链接是必需的
+        let title = self.title.extract_text(item)?;
+        let link = HtmlParser::resolve_url(&self.link.extract_text(item)?, base_url);
+
+        // 提取描述；html 模式下保留清理后的标记作为内容，描述使用其纯文本
+        let (description, content) = match self.description {
+            Some(ref field) if field.rules.html => field
+                .rules
+                .require(field.extract_text(item).map(|html| sanitize_html(&html, base_url)))?
+                .map_or((None, None), html_description),
+            _ => (JsonField::extract_optional(self.description.as_ref(), item)?, None),
+        };
+
+        let pub_date = match self.date {
+            Some(ref field) => field.rules.require(
+                field
+                    .extract_text(item)
+                    .and_then(|text| parse_date(&text, self.date_format.as_deref())),
+            )?,
+            None => None,
+        };
+
+        let author = JsonField::extract_optional(self.author.as_ref(), item)?;
+
+        let categories = match self.category {
+            Some(ref field) => {
+                let categories = field.extract_all(item, self.category_delimiter.as_deref());
+                field.rules.require(Some(categories).filter(|c| !c.is_empty()))?.unwrap_or_default()
+            }
+            None => Vec::new(),
+        };
+
+        let enclosure = JsonField::extract_optional(self.enclosure.as_ref(), item)?.map(|url| {
+            let url = HtmlParser::resolve_url(&url, base_url);
+            Enclosure {
+                mime_type: self
+                    .enclosure_type
+                    .clone()
+                    .unwrap_or_else(|| guess_mime_type(&url).to_string()),
+                url,
+                length: None,
+            }
+        });
+
+        let image = JsonField::extract_optional(self.image.as_ref(), item)?
+            .map(|url| HtmlParser::resolve_url(&url, base_url));
+
+        let guid = self.guid.as_ref().and_then(|field| field.extract_text(item));
+
+        let mut extra = BTreeMap::new();
+        for (name, field) in &self.extra {
+            if let Some(value) = JsonField::extract_optional(Some(field), item)? {
+                extra.insert(name.clone(), value);
+            }
+        }
+
+        Some(Article {
+            title,
+            link,
+            description,
+            content,
+            pub_date,
+            author,
+            categories,
+            guid,
+            enclosure,
+            image,
+            extra,
+        })
+    }
+}
+
+impl JsonField {
+    fn compile(name: &str, field: &FieldSelector) -> Result<Self> {
+        let paths = field
+            .selectors
+            .iter()
+            .map(|path| compile_path(name, path))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            paths,
+            rules: FieldRules::compile(name, field)?,
+        })
+    }
+
+    fn compile_optional(name: &str, field: Option<&FieldSelector>) -> Result<Option<Self>> {
+        field.map(|field| Self::compile(name, field)).transpose()
+    }
+
+    /// 按顺序尝试路径，对第一个非空值执行转换；结果为空时使用默认值
+    fn extract_text(&self, item: &Value) -> Option<String> {
+        let text = self
+            .paths
+            .iter()
+            .flat_map(|path| path.select(item))
+            .filter_map(scalar_text)
+            .find(|text| !text.is_empty());
+
+        self.rules.finish(text)
+    }
+
+    /// 返回第一个有值的路径匹配到的所有值（数组会展开），可按分隔符拆分，结果去重
+    fn extract_all(&self, item: &Value, delimiter: Option<&str>) -> Vec<String> {
+        let groups = self.paths.iter().map(|path| {
+            path.select(item).into_iter().flat_map(|value| match value {
+                Value::Array(items) => items.iter().filter_map(scalar_text).collect(),
+                other => scalar_text(other).into_iter().collect::<Vec<_>>(),
+            })
+        });
+
+        self.rules.collect_all(groups, delimiter)
+    }
+
+    /// 提取可选字段，返回 `None` 表示 required 字段缺失
+    fn extract_optional(field: Option<&Self>, item: &Value) -> Option<Option<String>> {
+        field.map_or(Some(None), |field| field.rules.require(field.extract_text(item)))
+    }
+}
+
+fn compile_path(name: &str, path: &str) -> Result<JsonPath> {
+    JsonPath::parse(path)
+        .map_err(|e| RssHubError::Config(format!("Invalid path '{}' in {}: {}", path, name, e)))
+}
+
+/// 字符串、数字和布尔值转为文本，其他类型视为无值
+fn scalar_text(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.trim().to_string()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+/// JSON 中的日期：Unix 时间戳（秒或毫秒）、RFC 3339 或其他常见格式
+fn parse_date(text: &str, format: Option<&str>) -> Option<DateTime<Utc>> {
+    let text = text.trim();
+
+    // 显式格式优先，像 `%Y%m%d` 这样的纯数字日期不能被当成时间戳
+    if let Some(date) = format.and_then(|format| HtmlParser::parse_date(text, Some(format))) {
+        return Some(date);
+    }
+
+    if !text.is_empty() && text.chars().all(|c| c.is_ascii_digit()) {
+        let timestamp: i64 = text.parse().ok()?;
+        // 13 位及以上视为毫秒
+        return if text.len() >= 13 {
+            DateTime::from_timestamp_millis(timestamp)
+        } else {
+            DateTime::from_timestamp(timestamp, 0)
+        };
+    }
+
+    HtmlParser::parse_date(text, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_path_select() {
+        let value = json!({"data": {"posts": [{"title": "A"}, {"title": "B"}]}});
+
+        let path = JsonPath::parse("$.data.posts[*].title").unwrap();
+        assert_eq!(path.select(&value), vec![&json!("A"), &json!("B")]);
+
+        let path = JsonPath::parse("data['posts'][1].title").unwrap();
+        assert_eq!(path.select(&value), vec![&json!("B")]);
+
+        assert_eq!(JsonPath::parse("$").unwrap().select(&value), vec![&value]);
+    }
+
+    #[test]
+    fn test_path_invalid() {
+        assert!(JsonPath::parse("posts[").is_err());
+        assert!(JsonPath::parse("posts..title").is_err());
+    }
+
+    #[test]
+    fn test_parse_timestamp() {
+        let seconds = parse_date("1704067200", None).unwrap();
+        let millis = parse_date("1704067200000", None).unwrap();
+
+        assert_eq!(seconds, millis);
+        assert_eq!(seconds.to_rfc3339(), "2024-01-01T00:00:00+00:00");
+    }
+
+    #[test]
+    fn test_parse_compact_date_with_format() {
+        // 纯数字但配置了格式时按格式解析，而不是当作时间戳
+        let date = parse_date("20240115", Some("%Y%m%d")).unwrap();
+        assert_eq!(date.to_rfc3339(), "2024-01-15T00:00:00+00:00");
+
+        // 格式不匹配时仍回退到时间戳
+        let date = parse_date("1704067200", Some("%Y-%m-%d")).unwrap();
+        assert_eq!(date.to_rfc3339(), "2024-01-01T00:00:00+00:00");
+    }
+}
@@ -0,0 +1,291 @@
+use crate::config::types::Article;
+use crate::error::Result;
+use crate::parser::compiled::Deadline;
+use crate::parser::html::HtmlParser;
+use crate::parser::link::normalize_link;
+use crate::parser::sanitize::sanitize_html;
+use scraper::{Html, Selector};
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+
+/// 视为文章的 schema.org 类型
+const ARTICLE_TYPES: [&str; 9] = [
+    "Article",
+    "BlogPosting",
+    "NewsArticle",
+    "TechArticle",
+    "ScholarlyArticle",
+    "Report",
+    "SocialMediaPosting",
+    "LiveBlogPosting",
+    "PodcastEpisode",
+];
+
+/// 从文档的 `<script type="application/ld+json">` 中提取文章
+///
+/// 支持单个对象、数组、`@graph` 以及 `ItemList`。无法解析的脚本会被忽略，
+/// 同一链接只保留第一次出现的条目。最多返回 `limit` 条，超过截止时间时返回 `ParseError`。
+pub fn extract_articles(document: &Html, base_url: &str, limit: usize, deadline: &Deadline) -> Result<Vec<Article>> {
+    let selector = Selector::parse(r#"script[type="application/ld+json"]"#).expect("valid selector");
+
+    let mut values: Vec<Value> = Vec::new();
+    for script in document.select(&selector) {
+        deadline.check()?;
+        if let Ok(value) = serde_json::from_str(script.text().collect::<String>().trim()) {
+            values.push(value);
+        }
+    }
+
+    let mut nodes = Vec::new();
+    for value in &values {
+        collect_nodes(value, &mut nodes);
+    }
+
+    let mut seen = HashSet::new();
+    let mut articles = Vec::new();
+    for node in nodes {
+        if articles.len() >= limit {
+            break;
+        }
+        deadline.check()?;
+
+        if let Some(article) = node_to_article(node, base_url) {
+            if seen.insert(normalize_link(&article.link, &[])) {
+                articles.push(article);
+            }
+        }
+    }
+
+    Ok(articles)
+}
+
+/// 用 JSON-LD 条目补全文章中为空的字段，按规范化后的链接匹配
+pub fn enrich_articles(articles: &mut [Article], document: &Html, base_url: &str, deadline: &Deadline) -> Result<()> {
+    let candidates = extract_articles(document, base_url, usize::MAX, deadline)?;
+    if candidates.is_empty() {
+        return Ok(());
+    }
+
+    let by_link: HashMap<String, &Article> = candidates
+        .iter()
+        .map(|article| (normalize_link(&article.link, &[]), article))
+        .collect();
+
+    for article in articles.iter_mut() {
+        if let Some(source) = by_link.get(&normalize_link(&article.link, &[])) {
+            fill_missing(article, source);
+        }
+    }
+
+    Ok(())
+}
+
+/// 只填充目标文章中为空的字段
+pub fn fill_missing(article: &mut Article, source: &Article) {
+    if article.description.is_none() {
+        article.description = source.description.clone();
+    }
+    if article.content.is_none() {
+        article.content = source.content.clone();
+    }
+    if article.pub_date.is_none() {
+        article.pub_date = source.pub_date;
+    }
+    if article.author.is_none() {
+        article.author = source.author.clone();
+    }
+    if article.categories.is_empty() {
+        article.categories = source.categories.clone();
+    }
+    if article.image.is_none() {
+        article.image = source.image.clone();
+    }
+}
+
+/// 展开数组、`@graph` 和 `ItemList`，收集候选文章节点
+fn collect_nodes<'a>(value: &'a Value, nodes: &mut Vec<&'a Value>) {
+    match value {
+        Value::Array(items) => items.iter().for_each(|item| collect_nodes(item, nodes)),
+        Value::Object(map) => {
+            if let Some(graph) = map.get("@graph") {
+                collect_nodes(graph, nodes);
+            }
+
+            if has_type(value, &["ItemList"]) {
+                for element in map.get("itemListElement").map(as_list).unwrap_or_default() {
+                    // ListItem 既可以内嵌 item，也可以直接带 url 和 name
+                    match element.get("item") {
+                        Some(item) if item.is_object() => collect_nodes(item, nodes),
+                        _ => nodes.push(element),
+                    }
+                }
+            } else if has_type(value, &ARTICLE_TYPES) {
+                nodes.push(value);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn node_to_article(node: &Value, base_url: &str) -> Option<Article> {
+    let title = string_field(node, "headline").or_else(|| string_field(node, "name"))?;
+    let link = string_field(node, "url")
+        .or_else(|| node.get("mainEntityOfPage").and_then(id_or_string))
+        .or_else(|| string_field(node, "item"))
+        .or_else(|| string_field(node, "@id"))?;
+
+    let content = string_field(node, "articleBody")
+        .map(|body| sanitize_html(&body, base_url))
+        .filter(|body| !body.is_empty());
+
+    Some(Article {
+        title,
+        link: HtmlParser::resolve_url(&link, base_url),
+        description: string_field(node, "description"),
+        content,
+        pub_date: string_field(node, "datePublished").and_then(|date| HtmlParser::parse_date(&date, None)),
+        author: node.get("author").and_then(names),
+        categories: node.get("keywords").map(keywords).unwrap_or_default(),
+        guid: None,
+        enclosure: None,
+        image: node
+            .get("image")
+            .and_then(|image| as_list(image).into_iter().find_map(id_or_string))
+            .map(|image| HtmlParser::resolve_url(&image, base_url)),
+        extra: Default::default(),
+    })
+}
+
+/// `@type` 可以是字符串或字符串数组
+fn has_type(node: &Value, types: &[&str]) -> bool {
+    node.get("@type")
+        .map(as_list)
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|t| t.as_str())
+        .any(|t| types.contains(&t))
+}
+
+/// 把单个值或数组统一为列表
+fn as_list(value: &Value) -> Vec<&Value> {
+    match value {
+        Value::Array(items) => items.iter().collect(),
+        other => vec![other],
+    }
+}
+
+fn string_field(node: &Value, key: &str) -> Option<String> {
+    node.get(key)
+        .and_then(Value::as_str)
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+}
+
+/// 字符串，或带 `url` / `@id` 的对象
+fn id_or_string(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.trim().to_string()).filter(|s| !s.is_empty()),
+        Value::Object(_) => string_field(value, "url").or_else(|| string_field(value, "@id")),
+        _ => None,
+    }
+}
+
+/// 作者可以是字符串、带 `name` 的对象或它们的数组，多个作者以逗号连接
+fn names(value: &Value) -> Option<String> {
+    let names: Vec<String> = as_list(value)
+        .into_iter()
+        .filter_map(|author| match author {
+            Value::String(s) => Some(s.trim().to_string()),
+            Value::Object(_) => string_field(author, "name"),
+            _ => None,
+        })
+        .filter(|name| !name.is_empty())
+        .collect();
+
+    Some(names.join(", ")).filter(|names| !names.is_empty())
+}
+
+/// 关键词可以是逗号分隔的字符串或字符串数组
+fn keywords(value: &Value) -> Vec<String> {
+    let mut seen = HashSet::new();
+    as_list(value)
+        .into_iter()
+        .filter_map(Value::as_str)
+        .flat_map(|s| s.split(','))
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty() && seen.insert(s.clone()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(json: &str) -> Vec<Article> {
+        let html = format!(r#"<html><head><script type="application/ld+json">{}</script></head></html>"#, json);
+        extract_articles(&Html::parse_document(&html), "https://example.com", usize::MAX, &deadline()).unwrap()
+    }
+
+    fn deadline() -> Deadline {
+        Deadline::after(std::time::Duration::from_secs(60))
+    }
+
+    #[test]
+    fn test_blog_posting() {
+        let articles = parse(
+            r#"{
+                "@context": "https://schema.org",
+                "@type": "BlogPosting",
+                "headline": "Hello",
+                "url": "/hello",
+                "datePublished": "2024-01-02T10:00:00+08:00",
+                "author": [{"@type": "Person", "name": "Alice"}, "Bob"],
+                "image": {"@type": "ImageObject", "url": "https://example.com/a.png"},
+                "keywords": "rust, web"
+            }"#,
+        );
+
+        assert_eq!(articles.len(), 1);
+        let article = &articles[0];
+        assert_eq!(article.title, "Hello");
+        assert_eq!(article.link, "https://example.com/hello");
+        assert_eq!(article.pub_date.unwrap().to_rfc3339(), "2024-01-02T02:00:00+00:00");
+        assert_eq!(article.author.as_deref(), Some("Alice, Bob"));
+        assert_eq!(article.image.as_deref(), Some("https://example.com/a.png"));
+        assert_eq!(article.categories, vec!["rust", "web"]);
+    }
+
+    #[test]
+    fn test_graph_and_item_list() {
+        let articles = parse(
+            r#"{
+                "@graph": [
+                    {"@type": "WebSite", "name": "Site", "url": "https://example.com"},
+                    {"@type": "ItemList", "itemListElement": [
+                        {"@type": "ListItem", "position": 1, "url": "https://example.com/a", "name": "A"},
+                        {"@type": "ListItem", "position": 2, "item": {"@type": "NewsArticle", "headline": "B", "url": "https://example.com/b"}}
+                    ]},
+                    {"@type": "Article", "headline": "A again", "url": "https://example.com/a#top"}
+                ]
+            }"#,
+        );
+
+        let titles: Vec<_> = articles.iter().map(|a| a.title.as_str()).collect();
+        assert_eq!(titles, vec!["A", "B"]);
+    }
+
+    #[test]
+    fn test_invalid_json_ignored() {
+        assert!(parse("{ not json").is_empty());
+    }
+
+    #[test]
+    fn test_deadline_stops_extraction() {
+        let html = r#"<script type="application/ld+json">{"@type": "BlogPosting", "headline": "A", "url": "/a"}</script>"#;
+        let expired = Deadline::after(std::time::Duration::ZERO);
+        std::thread::sleep(std::time::Duration::from_millis(2));
+
+        let result = extract_articles(&Html::parse_document(html), "https://example.com", usize::MAX, &expired);
+        assert!(matches!(result, Err(crate::error::RssHubError::ParseError(_))));
+    }
+}
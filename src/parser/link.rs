@@ -0,0 +1,170 @@
+use url::Url;
+
+/// 规范化链接：去掉片段、删除匹配的查询参数并按参数名排序
+///
+/// `strip_params` 中以 `*` 结尾的规则按前缀匹配（如 `utm_*`），其余按参数名精确匹配，
+/// 都不区分大小写。无法解析的链接原样返回。
+pub fn normalize_link(link: &str, strip_params: &[String]) -> String {
+    let Ok(mut url) = Url::parse(link) else {
+        return link.to_string();
+    };
+
+    url.set_fragment(None);
+
+    let mut pairs: Vec<(String, String)> = url
+        .query_pairs()
+        .filter(|(name, _)| !is_stripped(name, strip_params))
+        .map(|(name, value)| (name.into_owned(), value.into_owned()))
+        .collect();
+
+    if pairs.is_empty() {
+        url.set_query(None);
+    } else {
+        // 稳定排序，同名参数保持原有顺序
+        pairs.sort_by(|a, b| a.0.cmp(&b.0));
+        url.query_pairs_mut().clear().extend_pairs(pairs);
+    }
+
+    url.to_string()
+}
+
+/// 常见的跟踪参数和会话 ID，规则格式与 `normalize_link` 相同
+pub const TRACKING_PARAMS: &[&str] = &[
+    "utm_*",
+    "fbclid",
+    "gclid",
+    "dclid",
+    "msclkid",
+    "yclid",
+    "igshid",
+    "mc_cid",
+    "mc_eid",
+    "_hsenc",
+    "_hsmi",
+    "mkt_tok",
+    "spm",
+    "spm_id_from",
+    "scm",
+    "jsessionid",
+    "phpsessid",
+    "aspsessionid*",
+    "sessionid",
+    "session_id",
+];
+
+/// 删除链接中匹配的查询参数和路径参数（如 `;jsessionid=...`）
+///
+/// 规则格式与 `normalize_link` 相同。其余参数的顺序和编码保持不变，
+/// 没有删除任何参数或无法解析的链接原样返回。
+pub fn strip_params(link: &str, rules: &[String]) -> String {
+    let Ok(mut url) = Url::parse(link) else {
+        return link.to_string();
+    };
+
+    let stripped = |name: &str| is_stripped(name, rules);
+    let mut changed = false;
+
+    if let Some(query) = url.query() {
+        let kept: Vec<&str> = query
+            .split('&')
+            .filter(|pair| {
+                let name = pair.split('=').next().unwrap_or_default();
+                let name = url::form_urlencoded::parse(name.as_bytes())
+                    .next()
+                    .map(|(name, _)| name.into_owned())
+                    .unwrap_or_default();
+                let keep = !pair.is_empty() && !stripped(&name);
+                changed |= !keep;
+                keep
+            })
+            .collect();
+
+        if changed {
+            let query = kept.join("&");
+            url.set_query(Some(&query).filter(|q| !q.is_empty()).map(|q| q.as_str()));
+        }
+    }
+
+    if url.path().contains(';') {
+        let path: Vec<String> = url
+            .path()
+            .split('/')
+            .map(|segment| {
+                let mut parts = segment.split(';');
+                let mut kept = vec![parts.next().unwrap_or_default()];
+                for param in parts {
+                    if stripped(param.split('=').next().unwrap_or_default()) {
+                        changed = true;
+                    } else {
+                        kept.push(param);
+                    }
+                }
+                kept.join(";")
+            })
+            .collect();
+
+        if changed {
+            url.set_path(&path.join("/"));
+        }
+    }
+
+    if changed {
+        url.to_string()
+    } else {
+        link.to_string()
+    }
+}
+
+/// 查询参数是否匹配任意一条删除规则，不区分大小写
+fn is_stripped(name: &str, strip_params: &[String]) -> bool {
+    strip_params.iter().any(|rule| match rule.strip_suffix('*') {
+        Some(prefix) => name
+            .get(..prefix.len())
+            .is_some_and(|head| head.eq_ignore_ascii_case(prefix)),
+        None => name.eq_ignore_ascii_case(rule),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_link() {
+        let strip = vec!["utm_*".to_string(), "ref".to_string()];
+        let link = "https://Example.com/post?utm_source=rss&b=2&ref=home&a=1#comments";
+
+        assert_eq!(normalize_link(link, &strip), "https://example.com/post?a=1&b=2");
+
+        // 参数名不区分大小写，与 `strip_params` 一致
+        let link = "https://example.com/post?UTM_Source=rss&Ref=home&a=1";
+        assert_eq!(normalize_link(link, &strip), "https://example.com/post?a=1");
+    }
+
+    #[test]
+    fn test_strip_params_keeps_order_and_encoding() {
+        let rules: Vec<String> = TRACKING_PARAMS.iter().map(|rule| rule.to_string()).collect();
+
+        assert_eq!(
+            strip_params("https://example.com/post?b=a%20b&UTM_Source=x&a=1&fbclid=y#top", &rules),
+            "https://example.com/post?b=a%20b&a=1#top"
+        );
+        assert_eq!(
+            strip_params("https://example.com/item.jsp;jsessionid=ABC123?spm=a.b", &rules),
+            "https://example.com/item.jsp"
+        );
+        assert_eq!(strip_params("https://example.com/post?id=1", &rules), "https://example.com/post?id=1");
+        assert_eq!(strip_params("/relative?utm_source=x", &rules), "/relative?utm_source=x");
+    }
+
+    #[test]
+    fn test_normalize_link_drops_empty_query() {
+        let strip = vec!["utm_*".to_string()];
+
+        assert_eq!(
+            normalize_link("https://example.com/post?utm_medium=feed", &strip),
+            "https://example.com/post"
+        );
+        assert_eq!(normalize_link("not a url", &strip), "not a url");
+    }
+}
@@ -0,0 +1,50 @@
+/// 根据 URL 扩展名推断媒体 MIME 类型，无法识别时为 `application/octet-stream`
+pub fn guess_mime_type(url: &str) -> &'static str {
+    // 去掉查询参数和片段后取最后一段路径的扩展名
+    let path = url.split(['?', '#']).next().unwrap_or_default();
+    let extension = path
+        .rsplit('/')
+        .next()
+        .and_then(|name| name.rsplit_once('.'))
+        .map(|(_, ext)| ext.to_ascii_lowercase())
+        .unwrap_or_default();
+
+    match extension.as_str() {
+        "mp3" => "audio/mpeg",
+        "m4a" => "audio/mp4",
+        "aac" => "audio/aac",
+        "ogg" | "oga" => "audio/ogg",
+        "opus" => "audio/opus",
+        "wav" => "audio/wav",
+        "flac" => "audio/flac",
+        "mp4" | "m4v" => "video/mp4",
+        "webm" => "video/webm",
+        "mov" => "video/quicktime",
+        "jpg" | "jpeg" => "image/jpeg",
+        "png" => "image/png",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "avif" => "image/avif",
+        "svg" => "image/svg+xml",
+        "pdf" => "application/pdf",
+        _ => "application/octet-stream",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_guess_mime_type() {
+        assert_eq!(guess_mime_type("https://example.com/ep1.mp3"), "audio/mpeg");
+        assert_eq!(guess_mime_type("https://example.com/a/Cover.JPG?w=600#top"), "image/jpeg");
+        assert_eq!(guess_mime_type("https://example.com/video.mp4"), "video/mp4");
+    }
+
+    #[test]
+    fn test_guess_mime_type_unknown() {
+        assert_eq!(guess_mime_type("https://example.com/download"), "application/octet-stream");
+        assert_eq!(guess_mime_type("https://cdn.example.com/stream"), "application/octet-stream");
+    }
+}
@@ -0,0 +1,137 @@
+use crate::config::types::Article;
+use crate::parser::html::HtmlParser;
+use scraper::{Html, Selector};
+use std::collections::HashSet;
+use url::Url;
+
+/// 各字段依次尝试的 `<meta>` 标签，`property` 与 `name` 都会匹配
+const DESCRIPTION: [&str; 3] = ["og:description", "description", "twitter:description"];
+const PUBLISHED: [&str; 6] = [
+    "article:published_time",
+    "og:published_time",
+    "datePublished",
+    "pubdate",
+    "publish_date",
+    "date",
+];
+const AUTHOR: [&str; 3] = ["author", "article:author", "twitter:creator"];
+const IMAGE: [&str; 4] = ["og:image", "og:image:url", "og:image:secure_url", "twitter:image"];
+const TAG: &str = "article:tag";
+
+/// 从详情页的 OpenGraph 和 `<meta>` 标签中提取文章字段
+///
+/// 返回的文章只有描述、日期、作者、分类和配图，用于补全列表页没有提取到的字段。
+pub fn extract_meta(document: &Html, base_url: &str) -> Article {
+    let selector = Selector::parse("meta[content]").expect("valid selector");
+
+    let tags: Vec<(String, String)> = document
+        .select(&selector)
+        .filter_map(|meta| {
+            let value = meta.value();
+            let key = value
+                .attr("property")
+                .or_else(|| value.attr("name"))
+                .or_else(|| value.attr("itemprop"))?;
+            let content = value.attr("content")?.trim();
+            (!content.is_empty()).then(|| (key.trim().to_ascii_lowercase(), content.to_string()))
+        })
+        .collect();
+
+    let first = |keys: &[&str]| {
+        keys.iter().find_map(|key| {
+            let key = key.to_ascii_lowercase();
+            tags.iter().find(|(k, _)| *k == key).map(|(_, content)| content.clone())
+        })
+    };
+
+    let mut seen = HashSet::new();
+    let categories = tags
+        .iter()
+        .filter(|(key, _)| key == TAG)
+        .map(|(_, content)| content.clone())
+        .filter(|tag| seen.insert(tag.clone()))
+        .collect();
+
+    Article {
+        description: first(&DESCRIPTION),
+        pub_date: first(&PUBLISHED).and_then(|date| HtmlParser::parse_date(&date, None)),
+        // `article:author` 常常是作者主页的链接，不能作为作者名
+        author: AUTHOR.iter().find_map(|key| {
+            first(&[key]).filter(|author| !author.starts_with("http://") && !author.starts_with("https://"))
+        }),
+        categories,
+        image: first(&IMAGE).map(|image| resolve(&image, base_url)),
+        ..Default::default()
+    }
+}
+
+/// 详情页 `<link rel="canonical">` 指向的绝对链接，只接受 http(s) 链接
+pub fn extract_canonical(document: &Html, base_url: &str) -> Option<String> {
+    let selector = Selector::parse("link[rel][href]").expect("valid selector");
+
+    document
+        .select(&selector)
+        .filter(|link| {
+            let rel = link.value().attr("rel").unwrap_or_default();
+            rel.split_ascii_whitespace().any(|rel| rel.eq_ignore_ascii_case("canonical"))
+        })
+        .find_map(|link| {
+            let href = link.value().attr("href")?.trim();
+            let url = Url::parse(base_url).ok()?.join(href).ok()?;
+            matches!(url.scheme(), "http" | "https").then(|| url.to_string())
+        })
+}
+
+/// 按详情页地址解析相对 URL
+fn resolve(url: &str, page_url: &str) -> String {
+    Url::parse(page_url)
+        .and_then(|base| base.join(url))
+        .map(String::from)
+        .unwrap_or_else(|_| HtmlParser::resolve_url(url, page_url))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_meta() {
+        let html = r#"
+        <html><head>
+            <meta property="og:description" content="OpenGraph summary">
+            <meta name="description" content="Plain summary">
+            <meta property="article:published_time" content="2024-03-01T08:00:00+08:00">
+            <meta property="article:author" content="https://example.com/authors/alice">
+            <meta name="author" content="Alice">
+            <meta property="article:tag" content="rust">
+            <meta property="article:tag" content="web">
+            <meta property="og:image" content="/cover.png">
+        </head></html>
+        "#;
+
+        let article = extract_meta(&Html::parse_document(html), "https://example.com/post");
+
+        assert_eq!(article.description.as_deref(), Some("OpenGraph summary"));
+        assert_eq!(article.pub_date.unwrap().to_rfc3339(), "2024-03-01T00:00:00+00:00");
+        assert_eq!(article.author.as_deref(), Some("Alice"));
+        assert_eq!(article.categories, vec!["rust", "web"]);
+        assert_eq!(article.image.as_deref(), Some("https://example.com/cover.png"));
+    }
+
+    #[test]
+    fn test_extract_canonical() {
+        let html = r#"
+        <html><head>
+            <link rel="alternate" href="/feed.xml">
+            <link rel="Canonical" href="/posts/hello">
+        </head></html>
+        "#;
+        let document = Html::parse_document(html);
+
+        assert_eq!(
+            extract_canonical(&document, "https://example.com/p?id=1&utm_source=x").as_deref(),
+            Some("https://example.com/posts/hello")
+        );
+        assert!(extract_canonical(&Html::parse_document("<html></html>"), "https://example.com/").is_none());
+    }
+}
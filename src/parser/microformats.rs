@@ -0,0 +1,295 @@
+use crate::config::types::Article;
+use crate::error::Result;
+use crate::parser::compiled::Deadline;
+use crate::parser::html::HtmlParser;
+use crate::parser::sanitize::{html_to_text, sanitize_html};
+use chrono::{DateTime, Utc};
+use scraper::{ElementRef, Html, Selector};
+use std::collections::HashSet;
+
+/// 没有 `p-name` 时，从正文截取标题的最大字符数
+const IMPLIED_NAME_CHARS: usize = 80;
+
+/// 各属性的 mf2 类名及 mf1（hAtom）兼容类名
+const NAME: &str = ".p-name, .entry-title";
+const URL: &str = ".u-url, .u-uid, a[rel~=bookmark]";
+const PUBLISHED: &str = ".dt-published, .published, .dt-updated, .updated";
+const AUTHOR: &str = ".p-author, .author";
+const AUTHOR_NAME: &str = ".p-name, .fn";
+const CONTENT: &str = ".e-content, .entry-content";
+const SUMMARY: &str = ".p-summary, .entry-summary";
+const CATEGORY: &str = ".p-category, a[rel~=tag]";
+const PHOTO: &str = ".u-photo, .u-featured";
+
+/// 预编译的微格式解析器
+///
+/// 从 h-entry（以及 mf1 的 hentry）中提取文章，不需要任何选择器配置。
+/// 嵌套在其他 h-entry 中的条目（如评论）会被忽略。
+#[derive(Debug, Clone)]
+pub struct MicroformatsParser {
+    entry: Selector,
+    name: Selector,
+    url: Selector,
+    any_link: Selector,
+    published: Selector,
+    author: Selector,
+    author_name: Selector,
+    content: Selector,
+    summary: Selector,
+    category: Selector,
+    photo: Selector,
+}
+
+impl Default for MicroformatsParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MicroformatsParser {
+    pub fn new() -> Self {
+        Self {
+            entry: selector(".h-entry, .hentry"),
+            name: selector(NAME),
+            url: selector(URL),
+            any_link: selector("a[href]"),
+            published: selector(PUBLISHED),
+            author: selector(AUTHOR),
+            author_name: selector(AUTHOR_NAME),
+            content: selector(CONTENT),
+            summary: selector(SUMMARY),
+            category: selector(CATEGORY),
+            photo: selector(PHOTO),
+        }
+    }
+
+    /// 提取文章，最多返回 `limit` 条，超过截止时间时返回 `ParseError`
+    pub fn extract_articles(
+        &self,
+        document: &Html,
+        base_url: &str,
+        limit: usize,
+        deadline: &Deadline,
+    ) -> Result<Vec<Article>> {
+        let entries = document
+            .select(&self.entry)
+            .filter(|entry| !entry.ancestors().filter_map(ElementRef::wrap).any(is_entry));
+
+        let mut articles = Vec::new();
+        for entry in entries {
+            if articles.len() >= limit {
+                break;
+            }
+            deadline.check()?;
+
+            articles.extend(self.parse_entry(entry, base_url));
+        }
+
+        Ok(articles)
+    }
+
+    fn parse_entry(&self, entry: ElementRef, base_url: &str) -> Option<Article> {
+        let content = property(entry, &self.content)
+            .map(|el| sanitize_html(&el.inner_html(), base_url))
+            .filter(|html| !html.is_empty());
+        let content_text = content.as_deref().map(html_to_text).filter(|text| !text.is_empty());
+
+        let summary = property(entry, &self.summary).map(text).filter(|text| !text.is_empty());
+
+        // 没有 p-name 的短文（note）用正文开头作为标题
+        let title = property(entry, &self.name)
+            .map(text)
+            .filter(|name| !name.is_empty())
+            .or_else(|| summary.as_deref().or(content_text.as_deref()).map(implied_name))?;
+
+        let link = property(entry, &self.url)
+            .and_then(url_value)
+            .or_else(|| entry.select(&self.any_link).find_map(url_value))?;
+
+        Some(Article {
+            title,
+            link: HtmlParser::resolve_url(&link, base_url),
+            description: summary.or(content_text),
+            content,
+            pub_date: property(entry, &self.published).and_then(date_value),
+            author: property(entry, &self.author).and_then(|el| self.author_name(el)),
+            categories: self.categories(entry),
+            guid: None,
+            enclosure: None,
+            image: property(entry, &self.photo)
+                .and_then(url_value)
+                .map(|url| HtmlParser::resolve_url(&url, base_url)),
+            extra: Default::default(),
+        })
+    }
+
+    /// 作者可以是纯文本，也可以是嵌套的 h-card
+    fn author_name(&self, element: ElementRef) -> Option<String> {
+        let name = element
+            .select(&self.author_name)
+            .next()
+            .map(text)
+            .unwrap_or_else(|| text(element));
+
+        Some(name).filter(|name| !name.is_empty())
+    }
+
+    fn categories(&self, entry: ElementRef) -> Vec<String> {
+        let mut seen = HashSet::new();
+
+        entry
+            .select(&self.category)
+            .filter(|el| owned_by(entry, *el))
+            .map(text)
+            .filter(|category| !category.is_empty() && seen.insert(category.clone()))
+            .collect()
+    }
+}
+
+/// 属于该条目的第一个属性元素，跳过嵌套在其他微格式根元素中的属性
+fn property<'a>(entry: ElementRef<'a>, selector: &Selector) -> Option<ElementRef<'a>> {
+    entry.select(selector).find(|el| owned_by(entry, *el))
+}
+
+/// 属性元素与条目之间没有其他微格式根元素（属性元素本身可以是根，如 `p-author h-card`）
+fn owned_by(entry: ElementRef, element: ElementRef) -> bool {
+    element
+        .ancestors()
+        .filter_map(ElementRef::wrap)
+        .take_while(|ancestor| ancestor.id() != entry.id())
+        .all(|ancestor| !is_root(ancestor))
+}
+
+fn is_entry(element: ElementRef) -> bool {
+    element.value().classes().any(|class| class == "h-entry" || class == "hentry")
+}
+
+fn is_root(element: ElementRef) -> bool {
+    element
+        .value()
+        .classes()
+        .any(|class| class.starts_with("h-") || class == "hentry" || class == "vcard")
+}
+
+fn text(element: ElementRef) -> String {
+    element.text().collect::<Vec<_>>().join(" ").split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn implied_name(text: &str) -> String {
+    let mut name: String = text.chars().take(IMPLIED_NAME_CHARS).collect();
+    if text.chars().count() > IMPLIED_NAME_CHARS {
+        name.push('…');
+    }
+    name
+}
+
+/// u-* 属性：`href`、`src`、`value`，否则为文本
+fn url_value(element: ElementRef) -> Option<String> {
+    let value = element.value();
+    value
+        .attr("href")
+        .or_else(|| value.attr("src"))
+        .or_else(|| value.attr("value"))
+        .map(|url| url.trim().to_string())
+        .or_else(|| Some(text(element)))
+        .filter(|url| !url.is_empty())
+}
+
+/// dt-* 属性：`datetime`、`title`（abbr）、`value`，否则为文本
+fn date_value(element: ElementRef) -> Option<DateTime<Utc>> {
+    let value = element.value();
+    let raw = value
+        .attr("datetime")
+        .or_else(|| value.attr("title"))
+        .or_else(|| value.attr("value"))
+        .map(str::to_string)
+        .unwrap_or_else(|| text(element));
+
+    HtmlParser::parse_date(&raw, None)
+}
+
+fn selector(selector: &str) -> Selector {
+    Selector::parse(selector).expect("valid microformats selector")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(html: &str) -> Vec<Article> {
+        MicroformatsParser::new()
+            .extract_articles(
+                &Html::parse_document(html),
+                "https://blog.example",
+                usize::MAX,
+                &Deadline::after(std::time::Duration::from_secs(60)),
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn test_h_entry() {
+        let html = r#"
+        <div class="h-feed">
+            <article class="h-entry">
+                <h1 class="p-name">Hello</h1>
+                <a class="u-url" href="/hello">permalink</a>
+                <time class="dt-published" datetime="2024-05-01T12:00:00+02:00">May 1</time>
+                <a class="p-author h-card" href="/"><img class="u-photo" src="/me.jpg"><span class="p-name">Alice</span></a>
+                <a class="p-category" href="/tag/rust">rust</a>
+                <div class="e-content"><p>Body</p></div>
+                <div class="h-entry"><span class="p-name">A reply</span><a class="u-url" href="/reply">r</a></div>
+            </article>
+        </div>
+        "#;
+
+        let articles = parse(html);
+
+        assert_eq!(articles.len(), 1);
+        let article = &articles[0];
+        assert_eq!(article.title, "Hello");
+        assert_eq!(article.link, "https://blog.example/hello");
+        assert_eq!(article.pub_date.unwrap().to_rfc3339(), "2024-05-01T10:00:00+00:00");
+        assert_eq!(article.author.as_deref(), Some("Alice"));
+        assert_eq!(article.categories, vec!["rust"]);
+        assert_eq!(article.content.as_deref(), Some("<p>Body</p>"));
+        // 作者 h-card 中的头像不是文章配图
+        assert!(article.image.is_none());
+    }
+
+    #[test]
+    fn test_note_without_name() {
+        let html = r#"
+        <div class="h-entry">
+            <p class="e-content">Just a short note</p>
+            <a class="u-url" href="https://blog.example/notes/1"><time class="dt-published" datetime="2024-05-02">May 2</time></a>
+        </div>
+        "#;
+
+        let articles = parse(html);
+
+        assert_eq!(articles[0].title, "Just a short note");
+        assert_eq!(articles[0].link, "https://blog.example/notes/1");
+        assert!(articles[0].pub_date.is_some());
+    }
+
+    #[test]
+    fn test_hentry_backcompat() {
+        let html = r#"
+        <div class="hentry">
+            <h2 class="entry-title"><a rel="bookmark" href="/old-post">Old post</a></h2>
+            <abbr class="published" title="2010-01-01">Jan 1</abbr>
+            <span class="author vcard"><span class="fn">Bob</span></span>
+            <div class="entry-summary">Summary</div>
+        </div>
+        "#;
+
+        let articles = parse(html);
+
+        assert_eq!(articles[0].title, "Old post");
+        assert_eq!(articles[0].link, "https://blog.example/old-post");
+        assert_eq!(articles[0].author.as_deref(), Some("Bob"));
+        assert_eq!(articles[0].description.as_deref(), Some("Summary"));
+        assert!(articles[0].pub_date.is_some());
+    }
+}
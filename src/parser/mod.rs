@@ -1,3 +1,20 @@
 pub mod html;
+pub mod media;
+pub mod meta;
+pub mod microformats;
+pub mod monitor;
+pub mod compiled;
+pub mod content;
+pub mod embedded;
+pub mod field;
+pub mod guid;
+pub mod json;
+pub mod jsonld;
+pub mod link;
+pub mod readability;
+pub mod sanitize;
+pub mod transform;
+pub mod url_date;
 
 pub use html::HtmlParser;
+pub use compiled::CompiledParser;
@@ -0,0 +1,171 @@
+use crate::config::types::MonitorConfig;
+use crate::error::{Result, RssHubError};
+use crate::parser::compiled::compile_selector;
+use crate::parser::sanitize::remove_elements;
+use ego_tree::iter::Edge;
+use scraper::{Html, Node, Selector};
+use similar::{ChangeTag, TextDiff};
+
+/// 换行的块级元素
+const BLOCK_ELEMENTS: [&str; 27] = [
+    "address", "article", "aside", "blockquote", "br", "dd", "div", "dl", "dt", "figcaption", "footer", "h1",
+    "h2", "h3", "h4", "h5", "h6", "header", "hr", "li", "main", "ol", "p", "pre", "section", "tr", "ul",
+];
+
+/// 文本不计入快照的元素
+const IGNORED_ELEMENTS: [&str; 4] = ["script", "style", "noscript", "template"];
+
+/// 差异中每处修改前后保留的上下文行数
+const CONTEXT_LINES: usize = 2;
+
+/// 预编译的页面监控规则
+#[derive(Debug, Clone)]
+pub struct CompiledMonitor {
+    selector: Selector,
+    cleanup: Vec<Selector>,
+}
+
+impl CompiledMonitor {
+    pub fn compile(config: &MonitorConfig) -> Result<Self> {
+        if config.selector.trim().is_empty() {
+            return Err(RssHubError::Config("monitor.selector cannot be empty".into()));
+        }
+
+        Ok(Self {
+            selector: compile_selector("monitor.selector", &config.selector)?,
+            cleanup: config
+                .cleanup_selectors
+                .iter()
+                .map(|selector| compile_selector("monitor.cleanup_selectors", selector))
+                .collect::<Result<_>>()?,
+        })
+    }
+
+    /// 被监控区域的文本快照，每个块级元素一行，空白已合并
+    ///
+    /// 选择器没有匹配到任何元素时返回 `None`
+    pub fn snapshot(&self, html: &str) -> Option<String> {
+        let mut document = Html::parse_document(html);
+        remove_elements(&mut document, &self.cleanup);
+
+        let mut matched = false;
+        let mut text = String::new();
+        for element in document.select(&self.selector) {
+            matched = true;
+            let mut ignored = 0usize;
+
+            for edge in element.traverse() {
+                match edge {
+                    Edge::Open(node) => match node.value() {
+                        Node::Element(el) if IGNORED_ELEMENTS.contains(&el.name()) => ignored += 1,
+                        Node::Element(el) if BLOCK_ELEMENTS.contains(&el.name()) => text.push('\n'),
+                        Node::Element(el) if matches!(el.name(), "td" | "th") => text.push(' '),
+                        Node::Text(t) if ignored == 0 => text.push_str(t),
+                        _ => {}
+                    },
+                    Edge::Close(node) => match node.value() {
+                        Node::Element(el) if IGNORED_ELEMENTS.contains(&el.name()) => ignored -= 1,
+                        Node::Element(el) if BLOCK_ELEMENTS.contains(&el.name()) => text.push('\n'),
+                        _ => {}
+                    },
+                }
+            }
+            text.push('\n');
+        }
+
+        matched.then(|| {
+            text.lines()
+                .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+                .filter(|line| !line.is_empty())
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+    }
+}
+
+/// 两次快照之间的差异
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnapshotDiff {
+    /// 按行对比的 HTML，新增行用 `<ins>`，删除行用 `<del>`
+    pub html: String,
+    pub added: usize,
+    pub removed: usize,
+}
+
+/// 按行比较两次快照，只保留修改处附近的上下文
+pub fn diff_snapshots(previous: &str, current: &str) -> SnapshotDiff {
+    let diff = TextDiff::from_lines(previous, current);
+    let (mut added, mut removed) = (0, 0);
+
+    let mut html = String::from("<pre>");
+    for (index, group) in diff.grouped_ops(CONTEXT_LINES).iter().enumerate() {
+        if index > 0 {
+            html.push_str("…\n");
+        }
+
+        for change in group.iter().flat_map(|op| diff.iter_changes(op)) {
+            let line = escape(change.value().trim_end_matches('\n'));
+            match change.tag() {
+                ChangeTag::Insert => {
+                    added += 1;
+                    html.push_str(&format!("<ins>+ {}</ins>\n", line));
+                }
+                ChangeTag::Delete => {
+                    removed += 1;
+                    html.push_str(&format!("<del>- {}</del>\n", line));
+                }
+                ChangeTag::Equal => html.push_str(&format!("  {}\n", line)),
+            }
+        }
+    }
+    html.push_str("</pre>");
+
+    SnapshotDiff { html, added, removed }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn monitor(selector: &str, cleanup: &[&str]) -> CompiledMonitor {
+        CompiledMonitor::compile(&MonitorConfig {
+            selector: selector.to_string(),
+            cleanup_selectors: cleanup.iter().map(|s| s.to_string()).collect(),
+            max_changes: 50,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_snapshot_lines() {
+        let html = r#"
+        <main class="pricing">
+            <h2>Pro</h2>
+            <p>Price: <b>$10</b> / month</p>
+            <ul><li>5   seats</li><li>Email support</li></ul>
+            <span class="updated">Updated 3 minutes ago</span>
+            <script>track()</script>
+        </main>
+        "#;
+
+        let snapshot = monitor("main.pricing", &[".updated"]).snapshot(html).unwrap();
+
+        assert_eq!(snapshot, "Pro\nPrice: $10 / month\n5 seats\nEmail support");
+        assert!(monitor("#missing", &[]).snapshot(html).is_none());
+    }
+
+    #[test]
+    fn test_diff_snapshots() {
+        let diff = diff_snapshots("Pro\nPrice: $10\n5 seats", "Pro\nPrice: $12 <beta>\n5 seats");
+
+        assert_eq!(diff.added, 1);
+        assert_eq!(diff.removed, 1);
+        assert!(diff.html.contains("<del>- Price: $10</del>"));
+        assert!(diff.html.contains("<ins>+ Price: $12 &lt;beta&gt;</ins>"));
+        assert!(diff.html.contains("  Pro\n"));
+    }
+}
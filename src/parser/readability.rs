@@ -0,0 +1,333 @@
+use crate::parser::sanitize::{html_to_text, remove_elements, sanitize_html};
+use regex::Regex;
+use ego_tree::NodeId;
+use scraper::{ElementRef, Html, Selector};
+use std::collections::HashMap;
+
+/// 段落文本少于该字符数时不参与评分
+const MIN_PARAGRAPH_CHARS: usize = 25;
+
+/// 提取结果的纯文本少于该字符数时视为失败
+const MIN_CONTENT_CHARS: usize = 100;
+
+/// 可能是正文的类名/ID
+const POSITIVE: &str = r"(?i)article|body|content|entry|hentry|h-entry|main|page|post|text|blog|story";
+
+/// 通常不是正文的类名/ID
+const NEGATIVE: &str = r"(?i)hidden|banner|combx|comment|com-|contact|foot|footer|footnote|masthead|media|meta|outbrain|promo|related|scroll|share|shoutbox|sidebar|skyscraper|sponsor|shopping|tags|tool|widget|nav|menu|breadcrumb|pagination|subscribe|newsletter|advert|\bads?\b";
+
+/// 评分时直接跳过的区域
+const UNLIKELY: &str = r"(?i)banner|breadcrumbs|combx|comment|community|cover-wrap|disqus|extra|footer|gdpr|header|legends|menu|related|remark|replies|rss|shoutbox|sidebar|skyscraper|social|sponsor|supplemental|ad-break|agegate|pagination|pager|popup|yom-remote|cookie|newsletter";
+
+/// 虽然匹配 `UNLIKELY`，但可能包含正文
+const MAYBE: &str = r"(?i)and|article|body|column|content|main|shadow";
+
+/// 评分时跳过的标签
+const SKIPPED_TAGS: [&str; 9] = ["nav", "aside", "footer", "header", "form", "script", "style", "noscript", "template"];
+
+/// 输出前移除的样板元素
+const BOILERPLATE: &str = "script, style, noscript, template, iframe, form, nav, aside, footer, button, input, select, textarea, svg";
+
+/// 可读性风格的正文提取器
+///
+/// 按文本密度和链接比例给 DOM 节点打分，选出得分最高的容器及其相关的兄弟节点，
+/// 去掉导航、评论、分享等样板内容后输出清理过的 HTML。
+#[derive(Debug, Clone)]
+pub struct Readability {
+    positive: Regex,
+    negative: Regex,
+    unlikely: Regex,
+    maybe: Regex,
+    paragraphs: Selector,
+    boilerplate: Selector,
+    all: Selector,
+}
+
+impl Default for Readability {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Readability {
+    pub fn new() -> Self {
+        Self {
+            positive: Regex::new(POSITIVE).expect("valid regex"),
+            negative: Regex::new(NEGATIVE).expect("valid regex"),
+            unlikely: Regex::new(UNLIKELY).expect("valid regex"),
+            maybe: Regex::new(MAYBE).expect("valid regex"),
+            paragraphs: Selector::parse("p, pre, td, blockquote, div").expect("valid selector"),
+            boilerplate: Selector::parse(BOILERPLATE).expect("valid selector"),
+            all: Selector::parse("*").expect("valid selector"),
+        }
+    }
+
+    /// 提取文档正文，返回清理后的 HTML；找不到足够长的正文时返回 `None`
+    pub fn extract(&self, document: &Html, base_url: &str) -> Option<String> {
+        let stats = text_stats(document);
+        let scores = self.score(document, &stats);
+
+        let (top_id, top_score) = scores
+            .iter()
+            .max_by(|a, b| a.1.total_cmp(b.1))
+            .map(|(id, score)| (*id, *score))?;
+        let top = ElementRef::wrap(document.tree.get(top_id)?)?;
+
+        let html = self.with_siblings(top, top_score, &scores, &stats);
+        let html = sanitize_html(&self.strip_boilerplate(&html), base_url);
+
+        let text_len = html_to_text(&html).chars().count();
+        (text_len >= MIN_CONTENT_CHARS).then_some(html)
+    }
+
+    /// 段落得分累加到父节点（全部）、祖父节点（1/2）和曾祖父节点（1/3），
+    /// 最后按链接密度折算
+    fn score(&self, document: &Html, stats: &HashMap<NodeId, TextStats>) -> HashMap<NodeId, f64> {
+        let mut scores: HashMap<NodeId, f64> = HashMap::new();
+
+        for paragraph in document.select(&self.paragraphs) {
+            // div 只在没有块级子元素时才当作段落
+            if paragraph.value().name() == "div" && has_block_children(paragraph) {
+                continue;
+            }
+            if self.in_unlikely_region(paragraph) {
+                continue;
+            }
+
+            let text = normalized_text(paragraph);
+            let len = text.chars().count();
+            if len < MIN_PARAGRAPH_CHARS {
+                continue;
+            }
+
+            let commas = text.matches([',', '，', '、']).count();
+            let score = 1.0 + commas as f64 + (len as f64 / 100.0).min(3.0);
+
+            let ancestors = paragraph.ancestors().filter_map(ElementRef::wrap).take(3);
+            for (level, ancestor) in ancestors.enumerate() {
+                if matches!(ancestor.value().name(), "html" | "body") {
+                    break;
+                }
+                let entry = scores
+                    .entry(ancestor.id())
+                    .or_insert_with(|| self.initial_score(ancestor));
+                *entry += score / (level as f64 + 1.0);
+            }
+        }
+
+        for (id, score) in scores.iter_mut() {
+            *score *= 1.0 - link_density(stats, *id);
+        }
+
+        scores
+    }
+
+    /// 按标签和类名/ID 给出初始分
+    fn initial_score(&self, element: ElementRef) -> f64 {
+        let tag_score = match element.value().name() {
+            "article" => 10.0,
+            "div" | "main" | "section" => 5.0,
+            "pre" | "td" | "blockquote" => 3.0,
+            "address" | "ol" | "ul" | "dl" | "dd" | "dt" | "li" | "form" => -3.0,
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "th" => -5.0,
+            _ => 0.0,
+        };
+
+        tag_score + self.class_weight(element)
+    }
+
+    fn class_weight(&self, element: ElementRef) -> f64 {
+        let mut weight = 0.0;
+
+        for value in [element.value().attr("class"), element.value().id()].into_iter().flatten() {
+            if self.negative.is_match(value) {
+                weight -= 25.0;
+            }
+            if self.positive.is_match(value) {
+                weight += 25.0;
+            }
+        }
+
+        weight
+    }
+
+    /// 元素自身或祖先是导航、页脚等区域
+    fn in_unlikely_region(&self, element: ElementRef) -> bool {
+        std::iter::once(element)
+            .chain(element.ancestors().filter_map(ElementRef::wrap))
+            .any(|el| {
+                if SKIPPED_TAGS.contains(&el.value().name()) {
+                    return true;
+                }
+                let signature = format!(
+                    "{} {}",
+                    el.value().attr("class").unwrap_or_default(),
+                    el.value().id().unwrap_or_default()
+                );
+                self.unlikely.is_match(&signature) && !self.maybe.is_match(&signature)
+            })
+    }
+
+    /// 得分最高的节点加上得分接近或本身像正文段落的兄弟节点
+    fn with_siblings(
+        &self,
+        top: ElementRef,
+        top_score: f64,
+        scores: &HashMap<NodeId, f64>,
+        stats: &HashMap<NodeId, TextStats>,
+    ) -> String {
+        let Some(parent) = top.parent().and_then(ElementRef::wrap) else {
+            return top.html();
+        };
+
+        let threshold = (top_score * 0.2).max(10.0);
+        let class_bonus = top.value().attr("class").filter(|class| !class.is_empty());
+
+        parent
+            .children()
+            .filter_map(ElementRef::wrap)
+            .filter(|sibling| {
+                if sibling.id() == top.id() {
+                    return true;
+                }
+
+                let mut score = scores.get(&sibling.id()).copied().unwrap_or_default();
+                if class_bonus.is_some() && sibling.value().attr("class") == class_bonus {
+                    score += top_score * 0.2;
+                }
+                if score >= threshold {
+                    return true;
+                }
+
+                if sibling.value().name() == "p" {
+                    let len = normalized_text(*sibling).chars().count();
+                    let density = link_density(stats, sibling.id());
+                    return len > 80 && density < 0.25;
+                }
+
+                false
+            })
+            .map(|sibling| sibling.html())
+            .collect()
+    }
+
+    /// 移除脚本、表单、导航以及类名像评论/分享/推荐的元素
+    fn strip_boilerplate(&self, html: &str) -> String {
+        let mut fragment = Html::parse_fragment(html);
+        remove_elements(&mut fragment, std::slice::from_ref(&self.boilerplate));
+
+        let stats = text_stats(&fragment);
+        let removed: Vec<_> = fragment
+            .select(&self.all)
+            .filter(|el| self.is_boilerplate(*el, &stats))
+            .map(|el| el.id())
+            .collect();
+
+        for id in removed {
+            if let Some(mut node) = fragment.tree.get_mut(id) {
+                node.detach();
+            }
+        }
+
+        fragment.root_element().inner_html()
+    }
+
+    /// 负面类名、链接密度高的短块
+    fn is_boilerplate(&self, element: ElementRef, stats: &HashMap<NodeId, TextStats>) -> bool {
+        let name = element.value().name();
+        if !matches!(name, "div" | "section" | "ul" | "ol" | "table" | "p" | "span") {
+            return false;
+        }
+
+        if self.class_weight(element) < 0.0 {
+            return true;
+        }
+
+        let len = stats.get(&element.id()).map_or(0, |stats| stats.text);
+        name != "p" && len < 200 && link_density(stats, element.id()) > 0.5
+    }
+}
+
+/// 节点内的文本长度及其中链接文本的长度，不计空白字符
+#[derive(Debug, Clone, Copy, Default)]
+struct TextStats {
+    text: usize,
+    links: usize,
+}
+
+/// 自底向上遍历一次文档，得到每个节点的文本统计
+///
+/// 逐个候选节点重新收集文本会随嵌套深度重复计算，大页面上代价很高
+fn text_stats(document: &Html) -> HashMap<NodeId, TextStats> {
+    let nodes: Vec<_> = document.tree.root().descendants().collect();
+    let mut stats: HashMap<NodeId, TextStats> = HashMap::with_capacity(nodes.len());
+
+    // 先序遍历的逆序保证子节点先于父节点处理
+    for node in nodes.iter().rev() {
+        let mut own = stats.get(&node.id()).copied().unwrap_or_default();
+        if let Some(text) = node.value().as_text() {
+            own.text += text.chars().filter(|c| !c.is_whitespace()).count();
+        }
+        if node.value().as_element().is_some_and(|element| element.name() == "a") {
+            own.links = own.text;
+        }
+        stats.insert(node.id(), own);
+
+        if let Some(parent) = node.parent() {
+            let parent = stats.entry(parent.id()).or_default();
+            parent.text += own.text;
+            parent.links += own.links;
+        }
+    }
+
+    stats
+}
+
+/// 链接文本占全部文本的比例
+fn link_density(stats: &HashMap<NodeId, TextStats>, id: NodeId) -> f64 {
+    match stats.get(&id) {
+        Some(stats) if stats.text > 0 => stats.links as f64 / stats.text as f64,
+        _ => 0.0,
+    }
+}
+
+fn has_block_children(element: ElementRef) -> bool {
+    element.children().filter_map(ElementRef::wrap).any(|child| {
+        matches!(
+            child.value().name(),
+            "div" | "p" | "pre" | "table" | "ul" | "ol" | "blockquote" | "section" | "article" | "h1" | "h2" | "h3"
+                | "h4" | "h5" | "h6" | "figure" | "dl"
+        )
+    })
+}
+
+fn normalized_text(element: ElementRef) -> String {
+    element.text().collect::<Vec<_>>().join(" ").split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_short_document_returns_none() {
+        let html = "<html><body><p>Too short to be an article.</p></body></html>";
+        let document = Html::parse_document(html);
+
+        assert!(Readability::new().extract(&document, "https://example.com").is_none());
+    }
+
+    #[test]
+    fn test_text_stats_link_density() {
+        let document = Html::parse_document(r#"<div id="nav"><a href="/a">Home</a> <a href="/b">Blog</a></div><div id="post"><p>Body text <a href="/c">link</a></p></div>"#);
+        let stats = text_stats(&document);
+        let find = |id: &str| {
+            let selector = Selector::parse(&format!("#{}", id)).unwrap();
+            document.select(&selector).next().unwrap().id()
+        };
+
+        assert_eq!(link_density(&stats, find("nav")), 1.0);
+        // "Bodytextlink" 中 "link" 占 4/12
+        assert!((link_density(&stats, find("post")) - 4.0 / 12.0).abs() < 1e-9);
+    }
+}
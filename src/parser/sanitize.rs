@@ -0,0 +1,73 @@
+use ammonia::{Builder, UrlRelative};
+use scraper::{Html, Selector};
+use url::Url;
+
+/// 清理 HTML 片段
+///
+/// 只保留白名单中的标签和属性，移除 script、style 和事件处理属性，
+/// 并把所有 `src`/`href` 改写为基于 `base_url` 的绝对 URL。
+pub fn sanitize_html(html: &str, base_url: &str) -> String {
+    let mut builder = Builder::default();
+
+    match Url::parse(base_url) {
+        Ok(base) => builder.url_relative(UrlRelative::RewriteWithBase(base)),
+        Err(_) => builder.url_relative(UrlRelative::Deny),
+    };
+
+    builder.clean(html).to_string().trim().to_string()
+}
+
+/// HTML 片段的纯文本内容
+pub fn html_to_text(html: &str) -> String {
+    let fragment = Html::parse_fragment(html);
+    fragment
+        .root_element()
+        .text()
+        .collect::<Vec<_>>()
+        .join("")
+        .trim()
+        .to_string()
+}
+
+/// 从文档中移除所有匹配任一选择器的元素（连同其子树）
+pub fn remove_elements(document: &mut Html, selectors: &[Selector]) {
+    let ids: Vec<_> = selectors
+        .iter()
+        .flat_map(|selector| document.select(selector).map(|el| el.id()).collect::<Vec<_>>())
+        .collect();
+
+    for id in ids {
+        if let Some(mut node) = document.tree.get_mut(id) {
+            node.detach();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_removes_scripts_and_handlers() {
+        let html = r#"<p onclick="alert(1)">Hello<script>alert(2)</script><style>p{}</style></p>"#;
+
+        let clean = sanitize_html(html, "https://example.com/blog/");
+
+        assert_eq!(clean, "<p>Hello</p>");
+    }
+
+    #[test]
+    fn test_sanitize_absolutizes_urls() {
+        let html = r#"<a href="/post/1">Post</a><img src="img/a.png" alt="A">"#;
+
+        let clean = sanitize_html(html, "https://example.com/blog/");
+
+        assert!(clean.contains(r#"href="https://example.com/post/1""#));
+        assert!(clean.contains(r#"src="https://example.com/blog/img/a.png""#));
+    }
+
+    #[test]
+    fn test_html_to_text() {
+        assert_eq!(html_to_text("<p>Hello <b>World</b></p>"), "Hello World");
+    }
+}
@@ -0,0 +1,159 @@
+use crate::config::types::Transform;
+use crate::error::{Result, RssHubError};
+use regex::Regex;
+
+/// 预编译的转换步骤，正则只在加载配置时编译一次
+#[derive(Debug, Clone)]
+pub enum CompiledTransform {
+    Regex { re: Regex, group: usize },
+    Replace { re: Regex, replacement: String },
+    Trim,
+    Lowercase,
+    Uppercase,
+    Prefix(String),
+    Suffix(String),
+    Substring { start: usize, end: Option<usize> },
+}
+
+impl CompiledTransform {
+    pub fn compile(transform: &Transform) -> Result<Self> {
+        let compiled = match transform {
+            Transform::Regex { pattern, group } => {
+                let re = compile_regex(pattern)?;
+                // 未指定分组时有分组取第 1 组，否则取整个匹配
+                let group = group.unwrap_or(if re.captures_len() > 1 { 1 } else { 0 });
+                if group >= re.captures_len() {
+                    return Err(RssHubError::Config(format!(
+                        "Regex '{}' has no capture group {}",
+                        pattern, group
+                    )));
+                }
+                CompiledTransform::Regex { re, group }
+            }
+            Transform::Replace { pattern, replacement } => CompiledTransform::Replace {
+                re: compile_regex(pattern)?,
+                replacement: replacement.clone(),
+            },
+            Transform::Trim => CompiledTransform::Trim,
+            Transform::Lowercase => CompiledTransform::Lowercase,
+            Transform::Uppercase => CompiledTransform::Uppercase,
+            Transform::Prefix { value } => CompiledTransform::Prefix(value.clone()),
+            Transform::Suffix { value } => CompiledTransform::Suffix(value.clone()),
+            Transform::Substring { start, end } => {
+                if let Some(end) = end {
+                    if end < start {
+                        return Err(RssHubError::Config(format!(
+                            "Substring end ({}) must not be less than start ({})",
+                            end, start
+                        )));
+                    }
+                }
+                CompiledTransform::Substring { start: *start, end: *end }
+            }
+        };
+
+        Ok(compiled)
+    }
+
+    pub fn apply(&self, value: &str) -> String {
+        match self {
+            CompiledTransform::Regex { re, group } => re
+                .captures(value)
+                .and_then(|caps| caps.get(*group))
+                .map(|m| m.as_str().to_string())
+                .unwrap_or_default(),
+            CompiledTransform::Replace { re, replacement } => {
+                re.replace_all(value, replacement.as_str()).into_owned()
+            }
+            CompiledTransform::Trim => value.trim().to_string(),
+            CompiledTransform::Lowercase => value.to_lowercase(),
+            CompiledTransform::Uppercase => value.to_uppercase(),
+            CompiledTransform::Prefix(prefix) => format!("{}{}", prefix, value),
+            CompiledTransform::Suffix(suffix) => format!("{}{}", value, suffix),
+            CompiledTransform::Substring { start, end } => {
+                let chars = value.chars().skip(*start);
+                match end {
+                    Some(end) => chars.take(end - start).collect(),
+                    None => chars.collect(),
+                }
+            }
+        }
+    }
+}
+
+/// 编译一组转换步骤
+pub fn compile_transforms(transforms: &[Transform]) -> Result<Vec<CompiledTransform>> {
+    transforms.iter().map(CompiledTransform::compile).collect()
+}
+
+/// 依次执行字段转换步骤
+pub fn apply_transforms(value: &str, transforms: &[CompiledTransform]) -> String {
+    let mut value = value.to_string();
+
+    for transform in transforms {
+        value = transform.apply(&value);
+    }
+
+    value
+}
+
+fn compile_regex(pattern: &str) -> Result<Regex> {
+    Regex::new(pattern)
+        .map_err(|e| RssHubError::Config(format!("Invalid regex '{}': {}", pattern, e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(value: &str, transforms: Vec<Transform>) -> String {
+        apply_transforms(value, &compile_transforms(&transforms).unwrap())
+    }
+
+    #[test]
+    fn test_regex_capture_first_group() {
+        let transforms = vec![Transform::Regex {
+            pattern: r"Published on (\d{4}-\d{2}-\d{2})".to_string(),
+            group: None,
+        }];
+
+        let result = run("Published on 2024-01-01 | 5 min read", transforms);
+        assert_eq!(result, "2024-01-01");
+    }
+
+    #[test]
+    fn test_replace_and_case() {
+        let transforms = vec![
+            Transform::Replace {
+                pattern: r"^Posted by\s+".to_string(),
+                replacement: String::new(),
+            },
+            Transform::Uppercase,
+        ];
+
+        let result = run("Posted by alice", transforms);
+        assert_eq!(result, "ALICE");
+    }
+
+    #[test]
+    fn test_substring_prefix_suffix() {
+        let transforms = vec![
+            Transform::Substring { start: 0, end: Some(5) },
+            Transform::Prefix { value: "[".to_string() },
+            Transform::Suffix { value: "]".to_string() },
+        ];
+
+        let result = run("Hello, World", transforms);
+        assert_eq!(result, "[Hello]");
+    }
+
+    #[test]
+    fn test_compile_invalid_regex() {
+        let transforms = vec![Transform::Regex {
+            pattern: "(unclosed".to_string(),
+            group: None,
+        }];
+
+        assert!(compile_transforms(&transforms).is_err());
+    }
+}
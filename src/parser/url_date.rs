@@ -0,0 +1,68 @@
+use crate::error::{Result, RssHubError};
+use chrono::{DateTime, NaiveDate, Utc};
+use regex::Regex;
+
+/// 从文章链接中提取日期的规则，如 `/2024/05/12/slug`
+#[derive(Debug, Clone)]
+pub struct UrlDatePattern {
+    regex: Regex,
+}
+
+impl UrlDatePattern {
+    /// 编译正则，缺少 `year` 或 `month` 命名分组时报错
+    pub fn compile(pattern: &str) -> Result<Self> {
+        let regex = Regex::new(pattern).map_err(|e| {
+            RssHubError::Config(format!("Invalid date_fallback.url_pattern '{}': {}", pattern, e))
+        })?;
+
+        let names: Vec<_> = regex.capture_names().flatten().collect();
+        for group in ["year", "month"] {
+            if !names.contains(&group) {
+                return Err(RssHubError::Config(format!(
+                    "date_fallback.url_pattern '{}' must contain a named group '{}'",
+                    pattern, group
+                )));
+            }
+        }
+
+        Ok(Self { regex })
+    }
+
+    /// 链接中的日期（UTC 零点），不匹配或日期无效时返回 `None`
+    pub fn extract(&self, link: &str) -> Option<DateTime<Utc>> {
+        let captures = self.regex.captures(link)?;
+        let number = |name: &str| captures.name(name).and_then(|m| m.as_str().parse::<u32>().ok());
+
+        let year = number("year")? as i32;
+        let month = number("month")?;
+        let day = number("day").unwrap_or(1);
+
+        NaiveDate::from_ymd_opt(year, month, day)?
+            .and_hms_opt(0, 0, 0)
+            .map(|date| date.and_utc())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_date_from_url() {
+        let pattern = UrlDatePattern::compile(r"/(?P<year>\d{4})/(?P<month>\d{2})(?:/(?P<day>\d{2}))?/").unwrap();
+
+        let date = pattern.extract("https://blog.example/2024/05/12/slug").unwrap();
+        assert_eq!(date.to_rfc3339(), "2024-05-12T00:00:00+00:00");
+
+        let date = pattern.extract("https://blog.example/2024/05/slug").unwrap();
+        assert_eq!(date.to_rfc3339(), "2024-05-01T00:00:00+00:00");
+
+        assert!(pattern.extract("https://blog.example/2024/13/01/slug").is_none());
+        assert!(pattern.extract("https://blog.example/about").is_none());
+    }
+
+    #[test]
+    fn test_pattern_requires_groups() {
+        assert!(UrlDatePattern::compile(r"/(\d{4})/(\d{2})/").is_err());
+    }
+}
@@ -0,0 +1,207 @@
+use crate::config::types::{Article, FilterConfig, FilterField, FilterMatch, FilterRule};
+use crate::error::{Result, RssHubError};
+use regex::{Regex, RegexBuilder};
+
+/// 预编译的条目过滤规则
+#[derive(Debug, Clone)]
+pub struct CompiledFilter {
+    match_mode: FilterMatch,
+    include: Vec<CompiledRule>,
+    exclude: Vec<CompiledRule>,
+}
+
+#[derive(Debug, Clone)]
+struct CompiledRule {
+    fields: Vec<FilterField>,
+    matcher: Matcher,
+}
+
+#[derive(Debug, Clone)]
+enum Matcher {
+    /// 不区分大小写时 `needle` 已转为小写
+    Contains { needle: String, case_sensitive: bool },
+    Regex(Regex),
+}
+
+impl CompiledFilter {
+    /// 编译过滤规则，无效的正则或不完整的规则在这里报错
+    pub fn compile(config: &FilterConfig) -> Result<Self> {
+        let compile_rules = |name: &str, rules: &[FilterRule]| {
+            rules
+                .iter()
+                .map(|rule| CompiledRule::compile(name, rule))
+                .collect::<Result<Vec<_>>>()
+        };
+
+        Ok(Self {
+            match_mode: config.match_mode,
+            include: compile_rules("filter.include", &config.include)?,
+            exclude: compile_rules("filter.exclude", &config.exclude)?,
+        })
+    }
+
+    /// 文章是否应当保留
+    pub fn matches(&self, article: &Article) -> bool {
+        if !self.exclude.is_empty() && self.combine(&self.exclude, article) {
+            return false;
+        }
+
+        self.include.is_empty() || self.combine(&self.include, article)
+    }
+
+    /// 保留通过过滤的文章，顺序不变
+    pub fn apply(&self, articles: Vec<Article>) -> Vec<Article> {
+        articles.into_iter().filter(|article| self.matches(article)).collect()
+    }
+
+    fn combine(&self, rules: &[CompiledRule], article: &Article) -> bool {
+        match self.match_mode {
+            FilterMatch::All => rules.iter().all(|rule| rule.matches(article)),
+            FilterMatch::Any => rules.iter().any(|rule| rule.matches(article)),
+        }
+    }
+}
+
+impl CompiledRule {
+    fn compile(name: &str, rule: &FilterRule) -> Result<Self> {
+        let matcher = match (&rule.contains, &rule.regex) {
+            (Some(needle), None) => Matcher::Contains {
+                needle: if rule.case_sensitive {
+                    needle.clone()
+                } else {
+                    needle.to_lowercase()
+                },
+                case_sensitive: rule.case_sensitive,
+            },
+            (None, Some(pattern)) => Matcher::Regex(
+                RegexBuilder::new(pattern)
+                    .case_insensitive(!rule.case_sensitive)
+                    .build()
+                    .map_err(|e| RssHubError::Config(format!("Invalid regex '{}' in {}: {}", pattern, name, e)))?,
+            ),
+            _ => {
+                return Err(RssHubError::Config(format!(
+                    "Each rule in {} needs exactly one of 'contains' or 'regex'",
+                    name
+                )))
+            }
+        };
+
+        if rule.fields.is_empty() {
+            return Err(RssHubError::Config(format!("Rule in {} has no fields", name)));
+        }
+
+        Ok(Self {
+            fields: rule.fields.clone(),
+            matcher,
+        })
+    }
+
+    /// 任一字段的任一值匹配即可
+    fn matches(&self, article: &Article) -> bool {
+        self.fields.iter().any(|field| {
+            let values: Vec<&str> = match field {
+                FilterField::Title => vec![article.title.as_str()],
+                FilterField::Description => article.description.as_deref().into_iter().collect(),
+                FilterField::Author => article.author.as_deref().into_iter().collect(),
+                FilterField::Category => article.categories.iter().map(String::as_str).collect(),
+                FilterField::Link => vec![article.link.as_str()],
+                FilterField::Extra(name) => article.extra.get(name).map(String::as_str).into_iter().collect(),
+            };
+
+            values.into_iter().any(|value| self.matcher.matches(value))
+        })
+    }
+}
+
+impl Matcher {
+    fn matches(&self, value: &str) -> bool {
+        match self {
+            Matcher::Contains { needle, case_sensitive: true } => value.contains(needle.as_str()),
+            Matcher::Contains { needle, case_sensitive: false } => value.to_lowercase().contains(needle.as_str()),
+            Matcher::Regex(regex) => regex.is_match(value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn article(title: &str, categories: &[&str]) -> Article {
+        Article {
+            title: title.to_string(),
+            link: format!("https://example.com/{}", title),
+            categories: categories.iter().map(|c| c.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    fn compile(yaml: &str) -> CompiledFilter {
+        CompiledFilter::compile(&serde_yaml::from_str(yaml).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_include_and_exclude() {
+        let filter = compile(
+            r#"
+            include:
+              - field: [title, category]
+                contains: "RUST"
+            exclude:
+              - field: title
+                regex: "^\\[sponsored\\]"
+            "#,
+        );
+
+        assert!(filter.matches(&article("Learning Rust", &[])));
+        assert!(filter.matches(&article("Async runtimes", &["rust"])));
+        assert!(!filter.matches(&article("Learning Go", &["go"])));
+        assert!(!filter.matches(&article("[Sponsored] Rust hosting", &[])));
+    }
+
+    #[test]
+    fn test_match_all() {
+        let filter = compile(
+            r#"
+            match: all
+            include:
+              - field: title
+                contains: "rust"
+              - field: category
+                regex: "^release$"
+                case_sensitive: true
+            "#,
+        );
+
+        assert!(filter.matches(&article("Rust 1.80", &["release"])));
+        assert!(!filter.matches(&article("Rust 1.80", &["Release"])));
+        assert!(!filter.matches(&article("Go 1.23", &["release"])));
+    }
+
+    #[test]
+    fn test_extra_field() {
+        let filter = compile(
+            r#"
+            include:
+              - field: extra.comments
+                regex: "^\\d{3,}$"
+            "#,
+        );
+        let mut popular = article("Popular", &[]);
+        popular.extra.insert("comments".to_string(), "120".to_string());
+        let mut quiet = article("Quiet", &[]);
+        quiet.extra.insert("comments".to_string(), "3".to_string());
+
+        assert!(filter.matches(&popular));
+        assert!(!filter.matches(&quiet));
+        assert!(!filter.matches(&article("Unknown", &[])));
+    }
+
+    #[test]
+    fn test_rule_requires_one_matcher() {
+        let config: FilterConfig = serde_yaml::from_str("include: [{ field: title }]").unwrap();
+
+        assert!(CompiledFilter::compile(&config).is_err());
+    }
+}
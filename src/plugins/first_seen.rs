@@ -0,0 +1,75 @@
+use crate::config::types::Article;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// 记录每个插件中没有日期的条目第一次出现的时间
+///
+/// 没有配置数据存储时使用。条目按 GUID（没有时按链接）区分。每次只保留本次结果中的条目，
+/// 已经从列表页消失的条目会被遗忘，内存占用不会无限增长。
+#[derive(Debug, Default)]
+pub struct FirstSeen {
+    plugins: Mutex<HashMap<String, HashMap<String, DateTime<Utc>>>>,
+}
+
+impl FirstSeen {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 为没有日期的文章填充第一次看到的时间
+    pub fn apply(&self, plugin: &str, articles: &mut [Article]) {
+        self.apply_at(plugin, articles, Utc::now());
+    }
+
+    fn apply_at(&self, plugin: &str, articles: &mut [Article], now: DateTime<Utc>) {
+        let mut plugins = self.plugins.lock().unwrap_or_else(|e| e.into_inner());
+        let previous = plugins.remove(plugin).unwrap_or_default();
+
+        let mut current = HashMap::new();
+        for article in articles.iter_mut().filter(|article| article.pub_date.is_none()) {
+            let key = article.guid.clone().unwrap_or_else(|| article.link.clone());
+            let seen = *current
+                .entry(key.clone())
+                .or_insert_with(|| previous.get(&key).copied().unwrap_or(now));
+            article.pub_date = Some(seen);
+        }
+
+        plugins.insert(plugin.to_string(), current);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn article(link: &str) -> Article {
+        Article {
+            title: link.to_string(),
+            link: link.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_first_seen_is_kept_across_fetches() {
+        let first_seen = FirstSeen::new();
+        let t0 = Utc::now();
+        let t1 = t0 + Duration::hours(1);
+
+        let mut first = vec![article("https://a.example/1")];
+        first_seen.apply_at("blog", &mut first, t0);
+        assert_eq!(first[0].pub_date, Some(t0));
+
+        let mut second = vec![article("https://a.example/2"), article("https://a.example/1")];
+        first_seen.apply_at("blog", &mut second, t1);
+        assert_eq!(second[0].pub_date, Some(t1));
+        assert_eq!(second[1].pub_date, Some(t0));
+
+        // 其他插件互不影响
+        let mut other = vec![article("https://a.example/1")];
+        first_seen.apply_at("other", &mut other, t1);
+        assert_eq!(other[0].pub_date, Some(t1));
+    }
+}
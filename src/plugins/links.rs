@@ -0,0 +1,122 @@
+use crate::config::types::{Article, LinksConfig};
+use crate::error::Result;
+use crate::parser::link::{strip_params, TRACKING_PARAMS};
+use crate::plugins::processor::{ProcessContext, Processor};
+use ammonia::Builder;
+use regex::Regex;
+use std::borrow::Cow;
+use std::sync::Arc;
+
+/// 预编译的链接清理规则
+///
+/// 作为处理器排在管道最前面，去重和过滤看到的都是清理后的链接
+#[derive(Debug, Clone)]
+pub struct LinkCleaner {
+    rules: Arc<Vec<String>>,
+    /// 清理正文时使用，只在编译时构建一次
+    html: Arc<Builder<'static>>,
+    /// 描述中的纯文本链接
+    text_links: Regex,
+}
+
+impl LinkCleaner {
+    pub fn compile(config: &LinksConfig) -> Self {
+        let builtin = TRACKING_PARAMS.iter().filter(|_| config.strip_tracking).map(|rule| rule.to_string());
+        let extra = config.strip_params.iter().map(|rule| rule.trim().to_string());
+        let rules: Arc<Vec<String>> = Arc::new(builtin.chain(extra).filter(|rule| !rule.is_empty()).collect());
+
+        let filter_rules = rules.clone();
+        let mut html = Builder::default();
+        html.attribute_filter(move |element, attribute, value| match (element, attribute) {
+            ("a", "href") => Some(Cow::Owned(strip_params(value, &filter_rules))),
+            _ => Some(Cow::Borrowed(value)),
+        });
+
+        Self {
+            rules,
+            html: Arc::new(html),
+            text_links: Regex::new(r#"https?://[^\s<>"']+"#).expect("valid link pattern"),
+        }
+    }
+
+    /// 删除链接中的跟踪参数
+    pub fn clean_link(&self, link: &str) -> String {
+        strip_params(link, &self.rules)
+    }
+
+    /// 清理 HTML 片段中所有 `<a href>` 的跟踪参数
+    pub fn clean_html(&self, html: &str) -> String {
+        self.html.clean(html).to_string()
+    }
+
+    /// 清理纯文本中出现的链接
+    pub fn clean_text(&self, text: &str) -> String {
+        self.text_links
+            .replace_all(text, |caps: &regex::Captures| self.clean_link(&caps[0]))
+            .into_owned()
+    }
+
+    /// 清理文章链接、描述和正文中的链接
+    pub fn apply(&self, article: &mut Article) {
+        if self.rules.is_empty() {
+            return;
+        }
+
+        article.link = self.clean_link(&article.link);
+        if let Some(ref description) = article.description {
+            article.description = Some(self.clean_text(description));
+        }
+        if let Some(ref content) = article.content {
+            article.content = Some(self.clean_html(content));
+        }
+    }
+}
+
+impl Processor for LinkCleaner {
+    fn name(&self) -> &str {
+        "links"
+    }
+
+    fn process(&self, mut articles: Vec<Article>, _context: &ProcessContext) -> Result<Vec<Article>> {
+        articles.iter_mut().for_each(|article| self.apply(article));
+        Ok(articles)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clean_article_links() {
+        let cleaner = LinkCleaner::compile(&LinksConfig {
+            strip_params: vec!["From".to_string()],
+            ..Default::default()
+        });
+        let mut article = Article {
+            link: "https://example.com/post?id=7&utm_source=rss&from=home".to_string(),
+            description: Some("Read https://example.com/y?utm_medium=rss&id=1 now".to_string()),
+            content: Some(r#"<p><a href="https://example.com/x?fbclid=abc&amp;page=2">x</a></p>"#.to_string()),
+            ..Default::default()
+        };
+
+        cleaner.apply(&mut article);
+
+        assert_eq!(article.link, "https://example.com/post?id=7");
+        assert_eq!(article.description.as_deref(), Some("Read https://example.com/y?id=1 now"));
+        assert!(article.content.unwrap().contains(r#"href="https://example.com/x?page=2""#));
+    }
+
+    #[test]
+    fn test_builtin_rules_can_be_disabled() {
+        let cleaner = LinkCleaner::compile(&LinksConfig {
+            strip_tracking: false,
+            strip_params: vec!["ref".to_string()],
+        });
+
+        assert_eq!(
+            cleaner.clean_link("https://example.com/?utm_source=x&ref=y"),
+            "https://example.com/?utm_source=x"
+        );
+    }
+}
@@ -1,3 +1,10 @@
+pub mod filter;
+pub mod first_seen;
+pub mod links;
+pub mod order;
+pub mod processor;
 pub mod registry;
+pub mod template;
 
-pub use registry::PluginRegistry;
+pub use processor::{ProcessContext, Processor, ProcessorRegistry};
+pub use registry::{LoadedPlugin, PluginRegistry};
@@ -0,0 +1,98 @@
+use crate::config::types::{Article, FeedSort};
+use crate::parser::link::normalize_link;
+use std::collections::HashSet;
+
+/// 去掉重复条目，保留第一次出现的条目，顺序不变
+///
+/// GUID 相同或规范化后的链接相同都视为重复，例如置顶区和正文列表中的同一篇文章。
+pub fn dedupe(articles: Vec<Article>) -> Vec<Article> {
+    let mut guids = HashSet::new();
+    let mut links = HashSet::new();
+
+    articles
+        .into_iter()
+        .filter(|article| {
+            let link = normalize_link(&article.link, &[]);
+            let duplicate = article.guid.as_ref().is_some_and(|guid| guids.contains(guid)) || links.contains(&link);
+
+            if let Some(ref guid) = article.guid {
+                guids.insert(guid.clone());
+            }
+            links.insert(link);
+
+            !duplicate
+        })
+        .collect()
+}
+
+/// 按排序方式调整条目顺序
+pub fn sort(articles: &mut [Article], sort: FeedSort) {
+    match sort {
+        FeedSort::DateDesc => sort_by_date_desc(articles),
+    }
+}
+
+/// 有日期的条目按发布时间从新到旧排列，没有日期的条目留在原来的位置
+///
+/// 稳定排序，日期相同的条目保持原有顺序。
+fn sort_by_date_desc(articles: &mut [Article]) {
+    let slots: Vec<usize> = articles
+        .iter()
+        .enumerate()
+        .filter(|(_, article)| article.pub_date.is_some())
+        .map(|(index, _)| index)
+        .collect();
+
+    let mut dated: Vec<Article> = slots.iter().map(|&index| std::mem::take(&mut articles[index])).collect();
+    dated.sort_by_key(|article| std::cmp::Reverse(article.pub_date));
+
+    for (index, article) in slots.into_iter().zip(dated) {
+        articles[index] = article;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, Utc};
+
+    fn article(link: &str) -> Article {
+        Article {
+            title: link.to_string(),
+            link: link.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn titles(articles: &[Article]) -> Vec<&str> {
+        articles.iter().map(|a| a.title.as_str()).collect()
+    }
+
+    #[test]
+    fn test_dedupe_by_link_and_guid() {
+        let mut pinned = article("https://example.com/a#top");
+        pinned.title = "pinned".to_string();
+        let mut same_guid = article("https://example.com/c");
+        same_guid.guid = Some("post-b".to_string());
+        let mut b = article("https://example.com/b");
+        b.guid = Some("post-b".to_string());
+
+        let result = dedupe(vec![pinned, b, article("https://example.com/a"), same_guid]);
+
+        assert_eq!(titles(&result), vec!["pinned", "https://example.com/b"]);
+    }
+
+    #[test]
+    fn test_sort_keeps_undated_in_place() {
+        let now = Utc::now();
+        let dated = |link: &str, age: i64| Article {
+            pub_date: Some(now - Duration::days(age)),
+            ..article(link)
+        };
+        let mut articles = vec![dated("old", 5), article("undated"), dated("new", 1), dated("mid", 3)];
+
+        sort(&mut articles, FeedSort::DateDesc);
+
+        assert_eq!(titles(&articles), vec!["new", "undated", "mid", "old"]);
+    }
+}
@@ -0,0 +1,284 @@
+use crate::config::types::{Article, FeedSort, FilterConfig, PluginConfig, ProcessorConfig};
+use crate::error::{Result, RssHubError};
+use crate::parser::compiled::CompiledParser;
+use crate::plugins::filter::CompiledFilter;
+use crate::plugins::order;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+/// 处理器运行时可以读取的 Feed 上下文
+#[derive(Debug, Clone, Copy)]
+pub struct ProcessContext<'a> {
+    pub config: &'a PluginConfig,
+    /// 数据源地址，用于解析相对链接
+    pub base_url: &'a str,
+}
+
+/// 文章后处理器
+///
+/// 插件解析出文章后，按 YAML 中 `processors` 的顺序依次调用，
+/// 每个处理器接收上一个处理器的输出。
+pub trait Processor: Send + Sync {
+    /// 处理器名称，用于日志
+    fn name(&self) -> &str;
+
+    fn process(&self, articles: Vec<Article>, context: &ProcessContext) -> Result<Vec<Article>>;
+}
+
+impl fmt::Debug for dyn Processor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Processor({})", self.name())
+    }
+}
+
+/// 根据 YAML 配置创建处理器
+pub type ProcessorFactory = Arc<dyn Fn(&ProcessorConfig) -> Result<Arc<dyn Processor>> + Send + Sync>;
+
+/// 按 `type` 查找处理器工厂，内置 `filter`、`limit`、`dedupe` 和 `sort`
+#[derive(Clone)]
+pub struct ProcessorRegistry {
+    factories: HashMap<String, ProcessorFactory>,
+}
+
+impl fmt::Debug for ProcessorRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.factories.keys()).finish()
+    }
+}
+
+impl Default for ProcessorRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ProcessorRegistry {
+    /// 包含全部内置处理器的注册表
+    pub fn new() -> Self {
+        let mut registry = Self {
+            factories: HashMap::new(),
+        };
+
+        registry.register("filter", |config| {
+            let filter: FilterConfig = config.options()?;
+            Ok(Arc::new(CompiledFilter::compile(&filter)?))
+        });
+        registry.register("limit", |config| {
+            let limit: Limit = config.options()?;
+            Ok(Arc::new(limit))
+        });
+        registry.register("dedupe", |_| Ok(Arc::new(Dedupe)));
+        registry.register("sort", |config| {
+            let sort: Sort = config.options()?;
+            Ok(Arc::new(sort))
+        });
+
+        registry
+    }
+
+    /// 注册处理器工厂，同名的处理器（包括内置处理器）会被替换
+    pub fn register<F>(&mut self, name: impl Into<String>, factory: F)
+    where
+        F: Fn(&ProcessorConfig) -> Result<Arc<dyn Processor>> + Send + Sync + 'static,
+    {
+        self.factories.insert(name.into(), Arc::new(factory));
+    }
+
+    /// 创建处理器，未知的 `type` 返回 `Config` 错误
+    pub fn build(&self, config: &ProcessorConfig) -> Result<Arc<dyn Processor>> {
+        let factory = self
+            .factories
+            .get(&config.kind)
+            .ok_or_else(|| RssHubError::Config(format!("Unknown processor type: {}", config.kind)))?;
+
+        factory(config)
+    }
+
+    /// 按顺序创建插件的全部处理器
+    pub fn build_all(&self, configs: &[ProcessorConfig]) -> Result<Vec<Arc<dyn Processor>>> {
+        configs.iter().map(|config| self.build(config)).collect()
+    }
+}
+
+/// 依次执行处理器
+pub fn run_pipeline(
+    processors: &[Arc<dyn Processor>],
+    mut articles: Vec<Article>,
+    context: &ProcessContext,
+) -> Result<Vec<Article>> {
+    for processor in processors {
+        articles = processor.process(articles, context)?;
+    }
+
+    Ok(articles)
+}
+
+impl Processor for CompiledFilter {
+    fn name(&self) -> &str {
+        "filter"
+    }
+
+    fn process(&self, articles: Vec<Article>, _context: &ProcessContext) -> Result<Vec<Article>> {
+        Ok(self.apply(articles))
+    }
+}
+
+/// 只保留前 `count` 条
+#[derive(Debug, Deserialize)]
+struct Limit {
+    count: usize,
+}
+
+impl Processor for Limit {
+    fn name(&self) -> &str {
+        "limit"
+    }
+
+    fn process(&self, mut articles: Vec<Article>, _context: &ProcessContext) -> Result<Vec<Article>> {
+        articles.truncate(self.count);
+        Ok(articles)
+    }
+}
+
+/// 按 `parser.guid` 的链接或哈希策略重新生成 GUID
+#[derive(Debug)]
+pub(crate) struct Guids {
+    pub(crate) parser: Arc<CompiledParser>,
+}
+
+impl Processor for Guids {
+    fn name(&self) -> &str {
+        "guid"
+    }
+
+    fn process(&self, mut articles: Vec<Article>, _context: &ProcessContext) -> Result<Vec<Article>> {
+        self.parser.apply_guids(&mut articles);
+        Ok(articles)
+    }
+}
+
+/// 按 GUID 或规范化链接去重
+#[derive(Debug)]
+pub(crate) struct Dedupe;
+
+impl Processor for Dedupe {
+    fn name(&self) -> &str {
+        "dedupe"
+    }
+
+    fn process(&self, articles: Vec<Article>, _context: &ProcessContext) -> Result<Vec<Article>> {
+        Ok(order::dedupe(articles))
+    }
+}
+
+/// 调整条目顺序
+#[derive(Debug, Deserialize)]
+pub(crate) struct Sort {
+    #[serde(default = "default_sort_order")]
+    pub(crate) order: FeedSort,
+}
+
+fn default_sort_order() -> FeedSort {
+    FeedSort::DateDesc
+}
+
+impl Processor for Sort {
+    fn name(&self) -> &str {
+        "sort"
+    }
+
+    fn process(&self, mut articles: Vec<Article>, _context: &ProcessContext) -> Result<Vec<Article>> {
+        order::sort(&mut articles, self.order);
+        Ok(articles)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plugin_config(processors: &str) -> PluginConfig {
+        let yaml = format!(
+            r#"
+plugin: {{ name: test, description: Test }}
+source: {{ type: html, url: "https://example.com" }}
+parser: {{ mode: jsonld }}
+feed: {{ title: Test, description: Test, link: "https://example.com" }}
+processors:
+{}
+"#,
+            processors
+        );
+        serde_yaml::from_str(&yaml).unwrap()
+    }
+
+    fn articles(titles: &[&str]) -> Vec<Article> {
+        titles
+            .iter()
+            .map(|title| Article {
+                title: title.to_string(),
+                link: format!("https://example.com/{}", title),
+                ..Default::default()
+            })
+            .collect()
+    }
+
+    /// 给标题加前缀的自定义处理器
+    struct Prefix(String);
+
+    impl Processor for Prefix {
+        fn name(&self) -> &str {
+            "prefix"
+        }
+
+        fn process(&self, mut articles: Vec<Article>, _context: &ProcessContext) -> Result<Vec<Article>> {
+            for article in &mut articles {
+                article.title = format!("{}{}", self.0, article.title);
+            }
+            Ok(articles)
+        }
+    }
+
+    #[test]
+    fn test_builtin_and_custom_processors() {
+        let config = plugin_config(
+            r#"
+  - type: filter
+    exclude: [{ field: title, contains: "ad" }]
+  - type: prefix
+    text: "> "
+  - type: limit
+    count: 2
+"#,
+        );
+
+        let mut registry = ProcessorRegistry::new();
+        registry.register("prefix", |config| {
+            #[derive(Deserialize)]
+            struct Options {
+                text: String,
+            }
+            let options: Options = config.options()?;
+            Ok(Arc::new(Prefix(options.text)))
+        });
+
+        let processors = registry.build_all(&config.processors).unwrap();
+        let context = ProcessContext {
+            config: &config,
+            base_url: "https://example.com",
+        };
+        let result = run_pipeline(&processors, articles(&["a", "ad", "b", "c"]), &context).unwrap();
+
+        let titles: Vec<_> = result.iter().map(|a| a.title.as_str()).collect();
+        assert_eq!(titles, vec!["> a", "> b"]);
+    }
+
+    #[test]
+    fn test_unknown_processor() {
+        let config = plugin_config("  - type: nope");
+
+        assert!(ProcessorRegistry::new().build_all(&config.processors).is_err());
+    }
+}
@@ -1,34 +1,132 @@
 use crate::error::{Result, RssHubError};
-use crate::config::types::{PluginConfig, RssFeed};
+use crate::config::types::{Article, ParserMode, PluginConfig, ProcessorConfig, RequestConfig, RssFeed, SourceConfig};
 use crate::config::ConfigParser;
 use crate::fetcher::HttpFetcher;
-use crate::parser::HtmlParser;
+use crate::parser::CompiledParser;
+use crate::plugins::filter::CompiledFilter;
+use crate::plugins::first_seen::FirstSeen;
+use crate::plugins::links::LinkCleaner;
+use crate::plugins::order;
+use crate::plugins::processor::{run_pipeline, Dedupe, Guids, ProcessContext, Processor, ProcessorRegistry, Sort};
+use crate::plugins::template::FeedTemplates;
+use crate::store::Store;
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use tokio::sync::{RwLock, Semaphore};
+use tokio::task::JoinSet;
 use std::time::SystemTime;
 
 pub struct PluginRegistry {
     config_parser: ConfigParser,
     http_fetcher: Arc<HttpFetcher>,
-    plugin_cache: Arc<RwLock<std::collections::HashMap<String, CachedPlugin>>>,
+    plugin_cache: Arc<RwLock<std::collections::HashMap<String, Arc<LoadedPlugin>>>>,
+    parse_permits: Arc<Semaphore>,
+    first_seen: FirstSeen,
+    store: Option<Arc<Store>>,
+    processors: ProcessorRegistry,
 }
 
-struct CachedPlugin {
-    config: PluginConfig,
-    loaded_at: SystemTime,
+/// 已加载的插件：配置、预编译的解析器和后处理器
+#[derive(Debug)]
+pub struct LoadedPlugin {
+    pub config: PluginConfig,
+    pub parser: Arc<CompiledParser>,
+    /// 按执行顺序排列的处理器：链接清理、GUID、去重、`filter`、`processors`、排序
+    pub processors: Vec<Arc<dyn Processor>>,
+    /// 抓取详情页之后执行的处理器：链接清理、GUID、去重
+    pub detail_processors: Vec<Arc<dyn Processor>>,
+    pub templates: FeedTemplates,
+    /// 编译时间，配置文件修改后重新加载，也用于区分不同版本的 Feed 缓存
+    pub loaded_at: SystemTime,
+}
+
+impl LoadedPlugin {
+    /// 使用内置处理器编译插件
+    pub fn new(config: PluginConfig) -> Result<Self> {
+        Self::with_processors(config, &ProcessorRegistry::new())
+    }
+
+    /// 编译插件的解析规则，并从注册表创建后处理器
+    pub fn with_processors(config: PluginConfig, registry: &ProcessorRegistry) -> Result<Self> {
+        let parser = Arc::new(CompiledParser::for_plugin(&config)?);
+
+        // 先清理链接再生成 GUID，去重时同一篇文章的链接和 GUID 一致
+        let links = config
+            .links
+            .as_ref()
+            .map(|links| Arc::new(LinkCleaner::compile(links)) as Arc<dyn Processor>);
+        let guids = parser
+            .regenerates_guids()
+            .then(|| Arc::new(Guids { parser: parser.clone() }) as Arc<dyn Processor>);
+        let dedupe = dedupes(&config).then(|| Arc::new(Dedupe) as Arc<dyn Processor>);
+        let prepare: Vec<Arc<dyn Processor>> = links.into_iter().chain(guids).chain(dedupe).collect();
+
+        let mut processors = prepare.clone();
+        if let Some(ref filter) = config.filter {
+            processors.push(Arc::new(CompiledFilter::compile(filter)?));
+        }
+        processors.extend(registry.build_all(&config.processors)?);
+        if let Some(order) = config.feed.sort {
+            processors.push(Arc::new(Sort { order }));
+        }
+
+        // 规范链接可能带有跟踪参数，也可能让列表页中不同的链接指向同一篇文章
+        let detail_processors = match parser.fetches_detail() {
+            true => prepare,
+            false => Vec::new(),
+        };
+        let templates = FeedTemplates::compile(&config)?;
+
+        Ok(Self {
+            config,
+            parser,
+            processors,
+            detail_processors,
+            templates,
+            loaded_at: SystemTime::now(),
+        })
+    }
 }
 
 impl PluginRegistry {
     pub fn new(configs_dir: String) -> Result<Self> {
+        let parallelism = std::thread::available_parallelism().map_or(4, |n| n.get());
+        Self::with_parse_concurrency(configs_dir, parallelism)
+    }
+
+    /// 创建注册表，并限制同时进行的 HTML 解析数量
+    pub fn with_parse_concurrency(configs_dir: String, max_parses: usize) -> Result<Self> {
         Ok(Self {
             config_parser: ConfigParser::new(configs_dir),
             http_fetcher: Arc::new(HttpFetcher::new()?),
             plugin_cache: Arc::new(RwLock::new(std::collections::HashMap::new())),
+            parse_permits: Arc::new(Semaphore::new(max_parses.max(1))),
+            first_seen: FirstSeen::new(),
+            store: None,
+            processors: ProcessorRegistry::new(),
         })
     }
 
-    /// 获取插件配置(带缓存和自动刷新)
-    pub async fn get_plugin(&self, name: &str) -> Result<PluginConfig> {
+    /// 注册自定义处理器，插件可以在 `processors` 中通过 `type: <name>` 使用
+    ///
+    /// 同名的内置处理器会被替换
+    pub fn with_processor<F>(mut self, name: impl Into<String>, factory: F) -> Self
+    where
+        F: Fn(&ProcessorConfig) -> Result<Arc<dyn Processor>> + Send + Sync + 'static,
+    {
+        self.processors.register(name, factory);
+        self
+    }
+
+    /// 使用持久化存储保存条目历史
+    pub fn with_store(mut self, store: Arc<Store>) -> Self {
+        self.store = Some(store);
+        self
+    }
+
+    /// 获取插件(带缓存和自动刷新)
+    ///
+    /// 解析器在加载或重新加载配置时编译一次，随插件一起缓存
+    pub async fn get_plugin(&self, name: &str) -> Result<Arc<LoadedPlugin>> {
         // 检查插件是否存在
         if !self.config_parser.plugin_exists(name) {
             return Err(RssHubError::PluginNotFound(name.to_string()));
@@ -41,28 +139,33 @@ impl PluginRegistry {
         if let Some(cached) = cache.get(name) {
             // 对比修改时间
             if cached.loaded_at >= current_mtime {
-                return Ok(cached.config.clone());
+                return Ok(cached.clone());
             }
         }
         drop(cache);
 
-        // 加载配置
+        // 加载配置并编译解析器
         let config = self.config_parser.load_plugin(name)?;
+        let plugin = Arc::new(LoadedPlugin::with_processors(config, &self.processors)?);
 
         // 更新缓存
         let mut cache = self.plugin_cache.write().await;
-        cache.insert(name.to_string(), CachedPlugin {
-            config: config.clone(),
-            loaded_at: SystemTime::now(),
-        });
+        cache.insert(name.to_string(), plugin.clone());
 
-        Ok(config)
+        Ok(plugin)
     }
 
     /// 执行插件
-    pub async fn execute_plugin(&self, config: &PluginConfig) -> Result<RssFeed> {
+    pub async fn execute_plugin(&self, plugin: &LoadedPlugin) -> Result<RssFeed> {
         // 获取内容
-        let html = self.http_fetcher.fetch_html(&config.source).await?;
+        let html = self.http_fetcher.fetch_html(&plugin.config.source).await?;
+
+        self.build_feed(plugin, html).await
+    }
+
+    /// 从抓取到的页面内容生成 Feed
+    async fn build_feed(&self, plugin: &LoadedPlugin, html: String) -> Result<RssFeed> {
+        let config = &plugin.config;
 
         // 提取基础URL用于处理相对链接
         let base_url = match &config.source {
@@ -71,8 +174,79 @@ impl PluginRegistry {
             crate::config::types::SourceConfig::Xml(cfg) => &cfg.url,
         };
 
-        // 解析
-        let articles = HtmlParser::parse(&html, &config.parser, base_url, config.feed.limit)?;
+        // 解析；监控模式下把页面变化作为条目
+        let mut articles = if plugin.parser.is_monitor() {
+            self.monitor_changes(plugin, html, base_url).await?
+        } else {
+            self.parse_blocking(plugin, html, base_url.clone()).await?
+        };
+
+        // 后处理之后再限制数量；监控模式的变化记录数由 `max_changes` 决定，也需要在这里限制
+        let context = ProcessContext { config, base_url };
+        if !plugin.processors.is_empty() {
+            articles = run_pipeline(&plugin.processors, articles, &context)?;
+        }
+        articles.truncate(config.feed.limit);
+
+        // 抓取详情页正文和元数据
+        if plugin.parser.fetches_detail() {
+            articles = self.fetch_details(plugin, articles).await;
+            // 详情页没有提供日期（或抓取失败）时再从链接推断
+            plugin.parser.fill_url_dates(&mut articles);
+            articles = run_pipeline(&plugin.detail_processors, articles, &context)?;
+        }
+
+        // 合并历史条目；仍然没有日期的条目使用第一次看到的时间
+        let first_seen = config.parser.date_fallback.first_seen;
+        match (&config.history, &self.store) {
+            (Some(history), Some(store)) => {
+                // sled 读写和序列化是同步的，放到阻塞线程池中执行
+                let (store, name, history, current) =
+                    (store.clone(), config.plugin.name.clone(), history.clone(), articles.clone());
+                let merged = tokio::task::spawn_blocking(move || {
+                    store.merge_history(&name, current, &history, first_seen)
+                })
+                .await;
+
+                match merged {
+                    Ok(Ok(merged)) => articles = merged,
+                    Ok(Err(e)) => tracing::warn!("Failed to merge history for {}: {}", config.plugin.name, e),
+                    Err(e) => tracing::warn!("History task for {} failed: {}", config.plugin.name, e),
+                }
+                // 历史条目按最后看到的时间排列，需要重新排序
+                if let Some(sort) = config.feed.sort {
+                    order::sort(&mut articles, sort);
+                }
+                articles.truncate(config.feed.limit);
+            }
+            (None, Some(store)) if first_seen => {
+                // 记录保存在存储中，服务重启或条目暂时消失后不会重新计时
+                let (store, name) = (store.clone(), config.plugin.name.clone());
+                let current = articles.clone();
+                let filled = tokio::task::spawn_blocking(move || {
+                    let mut current = current;
+                    store.fill_first_seen(&name, &mut current).map(|_| current)
+                })
+                .await;
+
+                match filled {
+                    Ok(Ok(filled)) => articles = filled,
+                    Ok(Err(e)) => {
+                        tracing::warn!("Failed to record first-seen times for {}: {}", config.plugin.name, e);
+                        self.first_seen.apply(&config.plugin.name, &mut articles);
+                    }
+                    Err(e) => {
+                        tracing::warn!("First-seen task for {} failed: {}", config.plugin.name, e);
+                        self.first_seen.apply(&config.plugin.name, &mut articles);
+                    }
+                }
+            }
+            _ if first_seen => self.first_seen.apply(&config.plugin.name, &mut articles),
+            _ => {}
+        }
+
+        // 最后渲染模板，过滤和历史使用原始字段
+        articles.iter_mut().for_each(|article| plugin.templates.apply(article));
 
         // 构建 Feed
         let feed = RssFeed {
@@ -86,6 +260,146 @@ impl PluginRegistry {
         Ok(feed)
     }
 
+    /// 生成页面快照并与存储中的上一次快照比较，每次变化生成一个条目，从新到旧
+    async fn monitor_changes(&self, plugin: &LoadedPlugin, html: String, base_url: &str) -> Result<Vec<Article>> {
+        let config = &plugin.config;
+        let store = self
+            .store
+            .as_ref()
+            .ok_or_else(|| RssHubError::Config("Monitor mode requires a data store".into()))?;
+        let max_changes = config.parser.monitor.as_ref().map_or(0, |monitor| monitor.max_changes);
+
+        let permit = self
+            .parse_permits
+            .clone()
+            .acquire_owned()
+            .await
+            .map_err(|e| RssHubError::ParseError(format!("Parse pool closed: {}", e)))?;
+        let parser = plugin.parser.clone();
+        let snapshot = tokio::task::spawn_blocking(move || {
+            let _permit = permit;
+            parser.snapshot(&html)
+        })
+        .await
+        .map_err(|e| RssHubError::ParseError(format!("Parse task failed: {}", e)))??;
+
+        // sled 读写是同步的，同样放到阻塞线程池中
+        let (store, name) = (store.clone(), config.plugin.name.clone());
+        let changes = tokio::task::spawn_blocking(move || store.record_snapshot(&name, &snapshot, max_changes))
+            .await
+            .map_err(|e| RssHubError::ParseError(format!("Snapshot task failed: {}", e)))??;
+
+        Ok(changes
+            .into_iter()
+            .map(|change| Article {
+                title: format!("{}: +{} / -{} lines", config.feed.title, change.added, change.removed),
+                link: base_url.to_string(),
+                description: Some(change.detected_at.format("%Y-%m-%d %H:%M:%S UTC").to_string()),
+                content: Some(change.diff),
+                pub_date: Some(change.detected_at),
+                // 所有变化的链接相同，用检测时间区分
+                guid: Some(format!("{}#{}", base_url, change.detected_at.timestamp_micros())),
+                ..Default::default()
+            })
+            .collect())
+    }
+
+    /// 在阻塞线程池中解析 HTML，避免大页面阻塞异步运行时
+    ///
+    /// 同时进行的解析数量受 `parse_permits` 限制
+    async fn parse_blocking(
+        &self,
+        plugin: &LoadedPlugin,
+        html: String,
+        base_url: String,
+    ) -> Result<Vec<Article>> {
+        let permit = self
+            .parse_permits
+            .clone()
+            .acquire_owned()
+            .await
+            .map_err(|e| RssHubError::ParseError(format!("Parse pool closed: {}", e)))?;
+
+        let parser = plugin.parser.clone();
+        // 有后处理器时先解析全部条目，处理后再限制数量
+        let limit = match plugin.processors.is_empty() {
+            true => plugin.config.feed.limit,
+            false => usize::MAX,
+        };
+
+        tokio::task::spawn_blocking(move || {
+            let _permit = permit;
+            parser.parse(&html, &base_url, limit)
+        })
+        .await
+        .map_err(|e| RssHubError::ParseError(format!("Parse task failed: {}", e)))?
+    }
+
+    /// 并发抓取并解析文章详情页，保持文章原有顺序
+    ///
+    /// 单篇文章抓取或解析失败时记录警告并保留列表页中的内容
+    async fn fetch_details(&self, plugin: &LoadedPlugin, mut articles: Vec<Article>) -> Vec<Article> {
+        // 详情页使用数据源的请求头和超时，但总是 GET
+        let (request, encoding) = match &plugin.config.source {
+            SourceConfig::Html(cfg) => (cfg.request.clone(), cfg.encoding.clone()),
+            SourceConfig::Json(cfg) => (cfg.request.clone(), "utf-8".to_string()),
+            SourceConfig::Xml(cfg) => (cfg.request.clone(), "utf-8".to_string()),
+        };
+        let request = Arc::new(RequestConfig { method: "GET".to_string(), ..request });
+        let encoding = Arc::new(encoding);
+        let fetch_permits = Arc::new(Semaphore::new(plugin.parser.detail_concurrency()));
+
+        let mut tasks = JoinSet::new();
+        for (index, article) in articles.iter().cloned().enumerate() {
+            let fetcher = self.http_fetcher.clone();
+            let parser = plugin.parser.clone();
+            let parse_permits = self.parse_permits.clone();
+            let fetch_permits = fetch_permits.clone();
+            let request = request.clone();
+            let encoding = encoding.clone();
+
+            tasks.spawn(async move {
+                let html = {
+                    let _permit = fetch_permits.acquire_owned().await.ok()?;
+                    match fetcher.fetch_url(&article.link, &request, &encoding).await {
+                        Ok(html) => html,
+                        Err(e) => {
+                            tracing::warn!("Failed to fetch article {}: {}", article.link, e);
+                            return None;
+                        }
+                    }
+                };
+
+                let permit = parse_permits.acquire_owned().await.ok()?;
+                let parsed = tokio::task::spawn_blocking(move || {
+                    let _permit = permit;
+                    let mut article = article;
+                    let link = article.link.clone();
+                    match parser.parse_detail(&html, &link, &mut article) {
+                        Ok(()) => Some(article),
+                        Err(e) => {
+                            tracing::warn!("Failed to parse article {}: {}", link, e);
+                            None
+                        }
+                    }
+                })
+                .await
+                .ok()
+                .flatten()?;
+
+                Some((index, parsed))
+            });
+        }
+
+        while let Some(result) = tasks.join_next().await {
+            if let Ok(Some((index, article))) = result {
+                articles[index] = article;
+            }
+        }
+
+        articles
+    }
+
     /// 列出所有插件
     pub async fn list_plugins(&self) -> Result<Vec<String>> {
         self.config_parser.list_plugins()
@@ -104,6 +418,11 @@ impl PluginRegistry {
     }
 }
 
+/// 是否按链接去重；监控模式下所有条目的链接相同
+fn dedupes(config: &PluginConfig) -> bool {
+    config.feed.dedupe && config.parser.mode != ParserMode::Monitor
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -115,6 +434,14 @@ mod tests {
         assert!(registry.is_ok());
     }
 
+    #[test]
+    fn test_registry_with_parse_concurrency() {
+        let temp_dir = std::env::temp_dir();
+        let registry = PluginRegistry::with_parse_concurrency(temp_dir.to_str().unwrap().to_string(), 0);
+        // 并发数至少为 1
+        assert_eq!(registry.unwrap().parse_permits.available_permits(), 1);
+    }
+
     #[tokio::test]
     async fn test_plugin_not_found() {
         let temp_dir = std::env::temp_dir();
@@ -139,6 +466,141 @@ mod tests {
         // Should not panic
     }
 
+    #[tokio::test]
+    async fn test_custom_processor() {
+        let configs_dir = std::env::temp_dir().join(format!("rsshub-processors-{}", std::process::id()));
+        std::fs::create_dir_all(&configs_dir).unwrap();
+        std::fs::write(
+            configs_dir.join("blog.yml"),
+            r#"
+plugin: { name: blog, description: Blog }
+source: { type: html, url: "https://example.com" }
+parser: { mode: jsonld }
+feed: { title: Blog, description: Blog, link: "https://example.com" }
+filter:
+  exclude: [{ contains: "ad" }]
+processors:
+  - type: noop
+"#,
+        )
+        .unwrap();
+
+        struct Noop;
+        impl Processor for Noop {
+            fn name(&self) -> &str {
+                "noop"
+            }
+
+            fn process(&self, articles: Vec<Article>, _context: &ProcessContext) -> Result<Vec<Article>> {
+                Ok(articles)
+            }
+        }
+
+        let registry = PluginRegistry::new(configs_dir.to_str().unwrap().to_string()).unwrap();
+        // 没有注册的处理器在加载插件时报错
+        assert!(registry.get_plugin("blog").await.is_err());
+
+        let registry = registry.with_processor("noop", |_| Ok(Arc::new(Noop)));
+        let plugin = registry.get_plugin("blog").await.unwrap();
+        let names: Vec<_> = plugin.processors.iter().map(|p| p.name()).collect();
+        assert_eq!(names, vec!["dedupe", "filter", "noop"]);
+
+        std::fs::remove_dir_all(&configs_dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_monitor_changes_limited_by_feed_limit() {
+        let config: PluginConfig = serde_yaml::from_str(
+            r#"
+plugin: { name: pricing, description: Pricing }
+source: { type: html, url: "https://example.com/pricing" }
+parser:
+  mode: monitor
+  monitor: { selector: "main", max_changes: 50 }
+feed: { title: Pricing, description: Pricing, link: "https://example.com/pricing", limit: 2 }
+"#,
+        )
+        .unwrap();
+        let plugin = LoadedPlugin::new(config).unwrap();
+        let registry = PluginRegistry::new(std::env::temp_dir().to_str().unwrap().to_string())
+            .unwrap()
+            .with_store(Arc::new(Store::temporary().unwrap()));
+
+        for price in 1..=5 {
+            let html = format!("<main><p>Pro: ${}</p></main>", price);
+            registry.build_feed(&plugin, html).await.unwrap();
+            tokio::time::sleep(std::time::Duration::from_millis(2)).await;
+        }
+        let feed = registry
+            .build_feed(&plugin, "<main><p>Pro: $6</p></main>".to_string())
+            .await
+            .unwrap();
+
+        // 存储中有 5 条变化，Feed 只输出最新的 2 条
+        assert_eq!(feed.articles.len(), 2);
+        assert_eq!(feed.articles[0].title, "Pricing: +1 / -1 lines");
+        assert!(feed.articles[0].content.as_deref().unwrap().contains("$6"));
+    }
+
+    #[tokio::test]
+    async fn test_multiple_lists_deduped_before_limit() {
+        let config: PluginConfig = serde_yaml::from_str(
+            r#"
+plugin: { name: front, description: Front page }
+source: { type: html, url: "https://example.com" }
+parser:
+  lists:
+    - { selector: "section.featured article", title_selector: "h1 a", link_selector: "h1 a" }
+    - { selector: "ul.latest li", title_selector: "a", link_selector: "a" }
+feed: { title: Front, description: Front, link: "https://example.com", limit: 3 }
+"#,
+        )
+        .unwrap();
+        let plugin = LoadedPlugin::new(config).unwrap();
+        let registry = PluginRegistry::new(std::env::temp_dir().to_str().unwrap().to_string()).unwrap();
+
+        let html = r#"
+            <section class="featured"><article><h1><a href="/post/2">Featured: Two</a></h1></article></section>
+            <ul class="latest">
+                <li><a href="/post/1">One</a></li>
+                <li><a href="/post/2">Two</a></li>
+                <li><a href="/post/3">Three</a></li>
+            </ul>
+        "#;
+        let feed = registry.build_feed(&plugin, html.to_string()).await.unwrap();
+
+        // 同时出现在两个区块的文章只保留第一次出现的那篇，去重之后再限制数量
+        let titles: Vec<_> = feed.articles.iter().map(|a| a.title.as_str()).collect();
+        assert_eq!(titles, vec!["Featured: Two", "One", "Three"]);
+    }
+
+    #[tokio::test]
+    async fn test_guid_generated_after_link_cleaning() {
+        let config: PluginConfig = serde_yaml::from_str(
+            r#"
+plugin: { name: blog, description: Blog }
+source: { type: html, url: "https://example.com" }
+parser:
+  list: { selector: "li", title_selector: "a", link_selector: "a" }
+  guid: { type: hash, fields: [link] }
+links: { strip_params: ["from"] }
+feed: { title: Blog, description: Blog, link: "https://example.com" }
+"#,
+        )
+        .unwrap();
+        let plugin = LoadedPlugin::new(config).unwrap();
+        let registry = PluginRegistry::new(std::env::temp_dir().to_str().unwrap().to_string()).unwrap();
+
+        let html = r#"<ul><li><a href="/a?from=home">A</a></li><li><a href="/a?from=feed">A</a></li></ul>"#;
+        let feed = registry.build_feed(&plugin, html.to_string()).await.unwrap();
+
+        // GUID 使用清理后的链接，同一篇文章只保留一次
+        let clean = plugin.parser.parse(r#"<ul><li><a href="/a">A</a></li></ul>"#, "https://example.com", 1).unwrap();
+        assert_eq!(feed.articles.len(), 1);
+        assert_eq!(feed.articles[0].link, "https://example.com/a");
+        assert_eq!(feed.articles[0].guid, clean[0].guid);
+    }
+
     #[tokio::test]
     async fn test_invalidate_all() {
         let temp_dir = std::env::temp_dir();
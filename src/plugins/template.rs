@@ -0,0 +1,235 @@
+use crate::config::types::{Article, PluginConfig};
+use crate::error::{Result, RssHubError};
+use chrono::format::{Item, StrftimeItems};
+
+/// `{date}` 默认的日期格式
+const DEFAULT_DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// 预编译的条目模板
+///
+/// 只支持 `{field}` 占位符和 `{{`、`}}` 转义，不执行任何表达式。
+/// 日期可以指定格式：`{date:%Y/%m/%d}`，自定义字段写作 `{extra.<name>}`。
+/// 没有值的字段渲染为空字符串。
+#[derive(Debug, Clone)]
+pub struct Template {
+    segments: Vec<Segment>,
+}
+
+#[derive(Debug, Clone)]
+enum Segment {
+    Literal(String),
+    Field(Field),
+}
+
+#[derive(Debug, Clone)]
+enum Field {
+    Title,
+    Link,
+    Description,
+    Author,
+    /// 第一个分类
+    Category,
+    /// 全部分类，以 `, ` 分隔
+    Categories,
+    Guid,
+    Date(String),
+    Extra(String),
+}
+
+impl Template {
+    /// 编译模板，未闭合的括号和未知的占位符返回 `Config` 错误
+    ///
+    /// `extra_fields` 是插件声明的自定义字段名，其他 `{extra.<name>}` 视为未知占位符
+    pub fn compile(name: &str, source: &str, extra_fields: &[&str]) -> Result<Self> {
+        let error = |message: String| RssHubError::Config(format!("Invalid {} '{}': {}", name, source, message));
+
+        let mut segments = Vec::new();
+        let mut literal = String::new();
+        let mut chars = source.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '{' if chars.peek() == Some(&'{') => {
+                    chars.next();
+                    literal.push('{');
+                }
+                '}' if chars.peek() == Some(&'}') => {
+                    chars.next();
+                    literal.push('}');
+                }
+                '{' => {
+                    let mut placeholder = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('}') => break,
+                            Some(c) => placeholder.push(c),
+                            None => return Err(error("unclosed '{'".into())),
+                        }
+                    }
+
+                    if !literal.is_empty() {
+                        segments.push(Segment::Literal(std::mem::take(&mut literal)));
+                    }
+                    segments.push(Segment::Field(Field::parse(&placeholder, extra_fields).map_err(error)?));
+                }
+                '}' => return Err(error("unmatched '}', use '}}' for a literal brace".into())),
+                c => literal.push(c),
+            }
+        }
+
+        if !literal.is_empty() {
+            segments.push(Segment::Literal(literal));
+        }
+
+        Ok(Self { segments })
+    }
+
+    pub fn render(&self, article: &Article) -> String {
+        self.segments
+            .iter()
+            .map(|segment| match segment {
+                Segment::Literal(text) => text.clone(),
+                Segment::Field(field) => field.value(article),
+            })
+            .collect::<String>()
+            .trim()
+            .to_string()
+    }
+}
+
+impl Field {
+    fn parse(placeholder: &str, extra_fields: &[&str]) -> std::result::Result<Self, String> {
+        let (name, format) = match placeholder.split_once(':') {
+            Some((name, format)) => (name.trim(), Some(format)),
+            None => (placeholder.trim(), None),
+        };
+
+        let field = match name {
+            "title" => Field::Title,
+            "link" => Field::Link,
+            "description" => Field::Description,
+            "author" => Field::Author,
+            "category" => Field::Category,
+            "categories" => Field::Categories,
+            "guid" => Field::Guid,
+            "date" => {
+                let format = format.unwrap_or(DEFAULT_DATE_FORMAT);
+                if StrftimeItems::new(format).any(|item| item == Item::Error) {
+                    return Err(format!("invalid date format '{}'", format));
+                }
+                return Ok(Field::Date(format.to_string()));
+            }
+            other => match other.strip_prefix("extra.") {
+                Some(extra) if extra_fields.contains(&extra) => Field::Extra(extra.to_string()),
+                _ => return Err(format!("unknown placeholder '{{{}}}'", placeholder)),
+            },
+        };
+
+        match format {
+            Some(_) => Err(format!("placeholder '{{{}}}' does not accept a format", name)),
+            None => Ok(field),
+        }
+    }
+
+    fn value(&self, article: &Article) -> String {
+        match self {
+            Field::Title => article.title.clone(),
+            Field::Link => article.link.clone(),
+            Field::Description => article.description.clone().unwrap_or_default(),
+            Field::Author => article.author.clone().unwrap_or_default(),
+            Field::Category => article.categories.first().cloned().unwrap_or_default(),
+            Field::Categories => article.categories.join(", "),
+            Field::Guid => article.guid.clone().unwrap_or_default(),
+            Field::Extra(name) => article.extra.get(name).cloned().unwrap_or_default(),
+            Field::Date(format) => article
+                .pub_date
+                .map(|date| date.format(format).to_string())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// `feed.title_template` 和 `feed.description_template`
+#[derive(Debug, Clone, Default)]
+pub struct FeedTemplates {
+    title: Option<Template>,
+    description: Option<Template>,
+}
+
+impl FeedTemplates {
+    pub fn compile(config: &PluginConfig) -> Result<Self> {
+        let extra_fields: Vec<&str> = config
+            .parser
+            .list_blocks()
+            .iter()
+            .flat_map(|list| list.extra_fields.keys().map(String::as_str))
+            .collect();
+        let compile = |name, source: &Option<String>| {
+            source
+                .as_deref()
+                .map(|source| Template::compile(name, source, &extra_fields))
+                .transpose()
+        };
+
+        Ok(Self {
+            title: compile("feed.title_template", &config.feed.title_template)?,
+            description: compile("feed.description_template", &config.feed.description_template)?,
+        })
+    }
+
+    /// 用原始字段渲染标题和描述，两个模板看到的都是渲染前的值
+    pub fn apply(&self, article: &mut Article) {
+        let title = self.title.as_ref().map(|template| template.render(article));
+        let description = self.description.as_ref().map(|template| template.render(article));
+
+        if let Some(title) = title {
+            article.title = title;
+        }
+        if let Some(description) = description {
+            article.description = Some(description).filter(|d| !d.is_empty());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    fn article() -> Article {
+        Article {
+            title: "Async Rust".to_string(),
+            link: "https://example.com/async".to_string(),
+            description: Some("An intro".to_string()),
+            author: Some("Alice".to_string()),
+            categories: vec!["rust".to_string(), "async".to_string()],
+            pub_date: Some(Utc.with_ymd_and_hms(2024, 5, 1, 8, 0, 0).unwrap()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_render() {
+        let template = Template::compile("t", "[{category}] {title} {{{date:%Y/%m/%d}}}", &[]).unwrap();
+        assert_eq!(template.render(&article()), "[rust] Async Rust {2024/05/01}");
+
+        let template = Template::compile("t", "{author} · {date} · {description} ({categories})", &[]).unwrap();
+        assert_eq!(template.render(&article()), "Alice · 2024-05-01 · An intro (rust, async)");
+
+        let mut scored = article();
+        scored.extra.insert("score".to_string(), "42".to_string());
+        let template = Template::compile("t", "{title} ({extra.score} points)", &["score"]).unwrap();
+        assert_eq!(template.render(&scored), "Async Rust (42 points)");
+
+        // 没有值的字段渲染为空
+        let template = Template::compile("t", "{title} {guid}", &[]).unwrap();
+        assert_eq!(template.render(&article()), "Async Rust");
+    }
+
+    #[test]
+    fn test_invalid_templates() {
+        for source in ["{titel}", "{title", "title}", "{title:%Y}", "{date:%Q}", "{extra.price}"] {
+            assert!(Template::compile("feed.title_template", source, &["score"]).is_err(), "{}", source);
+        }
+    }
+}
@@ -4,44 +4,94 @@ use axum::{
     response::{IntoResponse, Response},
     Json,
 };
-use serde::Deserialize;
 use std::sync::Arc;
+use std::time::Duration;
+use crate::config::types::RssFeed;
 use crate::error::RssHubError;
+use crate::fetcher::MemoryCache;
 use crate::generator::{RssGenerator, AtomGenerator};
+use crate::router::query::{FeedQuery, RssQuery};
+
+/// 缓存条目超过该数量时清理过期条目，仍然超过时移除最早的条目
+const MAX_CACHE_ENTRIES: usize = 1024;
 
 #[derive(Clone)]
 pub struct AppState {
     pub plugin_registry: Arc<crate::plugins::PluginRegistry>,
-}
-
-#[derive(Debug, Deserialize)]
-pub struct RssQuery {
-    #[serde(default)]
-    format: Option<String>,
+    pub feed_cache: Arc<MemoryCache>,
 }
 
 /// RSS 订阅处理器
+///
+/// 生成的内容按插件的 `cache` 配置缓存，缓存键包含插件版本和规范化后的查询参数；
+/// 插件抓取到的 Feed 每个版本另外缓存一份，不同的查询参数不会重复抓取
 pub async fn get_rss(
     State(state): State<AppState>,
     Path(plugin_name): Path<String>,
     Query(query): Query<RssQuery>,
 ) -> Result<Response, RssHubError> {
-    // 获取插件配置
-    let config = state.plugin_registry.get_plugin(&plugin_name).await?;
+    // 获取插件
+    let plugin = state.plugin_registry.get_plugin(&plugin_name).await?;
+
+    // 校验查询参数
+    let query = FeedQuery::compile(&query)?;
+    let cache = &plugin.config.cache;
+
+    // 缓存键包含插件的加载时间，修改配置后不再使用旧的 Feed
+    let loaded_at = plugin
+        .loaded_at
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos();
+    let feed_key = format!("{}@{}", plugin_name, loaded_at);
+    let cache_key = query.cache_key(&feed_key);
+
+    if cache.enabled {
+        if let Some(content) = state.feed_cache.get(&cache_key).await {
+            return Ok(feed_response(content));
+        }
+    }
 
-    // 执行插件
-    let feed = state.plugin_registry.execute_plugin(&config).await?;
+    let cached = match cache.enabled {
+        true => state.feed_cache.get(&feed_key).await,
+        false => None,
+    };
+    let mut feed = match cached.and_then(|content| serde_json::from_str::<RssFeed>(&content).ok()) {
+        Some(feed) => feed,
+        None => {
+            // 执行插件
+            let feed = state.plugin_registry.execute_plugin(&plugin).await?;
+            if cache.enabled {
+                let ttl = Duration::from_secs(cache.ttl);
+                state.feed_cache.set_with_ttl(feed_key, serde_json::to_string(&feed)?, ttl).await;
+            }
+            feed
+        }
+    };
+    query.apply(&mut feed);
 
     // 根据 format 返回不同格式
-    let content = match query.format.as_deref() {
-        Some("atom") => AtomGenerator::generate(&feed),
-        _ => RssGenerator::generate(&feed),
+    let content = if query.is_atom() {
+        AtomGenerator::generate(&feed)
+    } else {
+        RssGenerator::generate(&feed)
     };
 
-    Ok((
-        [(axum::http::header::CONTENT_TYPE, "application/xml; charset=utf-8")],
-        content,
-    ).into_response())
+    if cache.enabled {
+        state
+            .feed_cache
+            .set_with_ttl(cache_key, content.clone(), Duration::from_secs(cache.ttl))
+            .await;
+        if state.feed_cache.len().await > MAX_CACHE_ENTRIES {
+            state.feed_cache.shrink_to(MAX_CACHE_ENTRIES).await;
+        }
+    }
+
+    Ok(feed_response(content))
+}
+
+fn feed_response(content: String) -> Response {
+    ([(axum::http::header::CONTENT_TYPE, "application/xml; charset=utf-8")], content).into_response()
 }
 
 /// 列出所有插件
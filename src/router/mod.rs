@@ -1,17 +1,22 @@
 pub mod handlers;
+pub mod query;
 
 pub use handlers::{AppState, index, health_check, list_plugins, get_rss};
+pub use query::{FeedQuery, RssQuery};
 
 use axum::{
     Router,
     routing::get,
 };
 use std::sync::Arc;
+use crate::fetcher::MemoryCache;
 use crate::plugins::PluginRegistry;
 
 pub fn create_router(registry: Arc<PluginRegistry>) -> Router {
     let state = handlers::AppState {
         plugin_registry: registry,
+        // 每个条目使用插件自己的 `cache.ttl`
+        feed_cache: Arc::new(MemoryCache::new(0)),
     };
 
     Router::new()
@@ -0,0 +1,314 @@
+use crate::config::types::{Article, FeedSort, FilterConfig, FilterField, FilterMatch, FilterRule, RssFeed};
+use crate::error::{Result, RssHubError};
+use crate::parser::sanitize::html_to_text;
+use crate::plugins::filter::CompiledFilter;
+use crate::plugins::order;
+use chrono::{Duration, Utc};
+use serde::Deserialize;
+
+/// `/rss/:plugin_name` 的查询参数
+#[derive(Debug, Default, Deserialize)]
+pub struct RssQuery {
+    /// 输出格式：`rss`（默认）或 `atom`
+    #[serde(default)]
+    pub format: Option<String>,
+    /// 最多返回的条目数
+    #[serde(default)]
+    pub limit: Option<usize>,
+    /// 只保留标题或描述匹配该正则的条目
+    #[serde(default)]
+    pub filter: Option<String>,
+    /// 移除标题或描述匹配该正则的条目
+    #[serde(default)]
+    pub filterout: Option<String>,
+    /// 只保留标题匹配该正则的条目
+    #[serde(default)]
+    pub filter_title: Option<String>,
+    /// 只保留发布时间在该秒数以内的条目
+    #[serde(default)]
+    pub filter_time: Option<u64>,
+    /// 排序方式，目前只支持 `date`（按发布时间从新到旧）
+    #[serde(default)]
+    pub sort: Option<String>,
+    /// 把描述截断为前 N 个字符，并去掉全文内容
+    #[serde(default)]
+    pub brief: Option<usize>,
+}
+
+/// 编译后的查询参数
+///
+/// 在插件生成 Feed 之后按 过滤 → 时间 → 排序 → 数量 → 摘要 的顺序调整条目。
+#[derive(Debug)]
+pub struct FeedQuery {
+    atom: bool,
+    limit: Option<usize>,
+    filter: Option<CompiledFilter>,
+    filter_time: Option<u64>,
+    sort: Option<FeedSort>,
+    brief: Option<usize>,
+    /// 规范化后的参数，用于缓存键
+    normalized: String,
+}
+
+impl FeedQuery {
+    /// 校验并编译查询参数，无效的正则或排序方式返回 `Config` 错误
+    pub fn compile(query: &RssQuery) -> Result<Self> {
+        let non_empty = |value: &Option<String>| value.clone().filter(|v| !v.is_empty());
+        let filter = non_empty(&query.filter);
+        let filterout = non_empty(&query.filterout);
+        let filter_title = non_empty(&query.filter_title);
+
+        // 与插件的 `feed.sort: date_desc` 相同
+        let sort = match query.sort.as_deref() {
+            None | Some("") => None,
+            Some("date") => Some(FeedSort::DateDesc),
+            Some(other) => return Err(RssHubError::Config(format!("Unsupported sort: {}", other))),
+        };
+
+        let atom = query.format.as_deref() == Some("atom");
+        let limit = query.limit;
+        let filter_time = query.filter_time;
+        let brief = query.brief.filter(|n| *n > 0);
+
+        let regex_rule = |fields: Vec<FilterField>, pattern: &String| FilterRule {
+            fields,
+            regex: Some(pattern.clone()),
+            ..Default::default()
+        };
+        let mut config = FilterConfig {
+            match_mode: FilterMatch::All,
+            ..Default::default()
+        };
+        if let Some(ref pattern) = filter {
+            config.include.push(regex_rule(vec![FilterField::Title, FilterField::Description], pattern));
+        }
+        if let Some(ref pattern) = filter_title {
+            config.include.push(regex_rule(vec![FilterField::Title], pattern));
+        }
+        if let Some(ref pattern) = filterout {
+            config.exclude.push(regex_rule(vec![FilterField::Title, FilterField::Description], pattern));
+        }
+        let compiled_filter = if config.include.is_empty() && config.exclude.is_empty() {
+            None
+        } else {
+            Some(CompiledFilter::compile(&config)?)
+        };
+
+        // 固定顺序、去掉默认值，同义的请求得到相同的缓存键
+        let mut normalized = url::form_urlencoded::Serializer::new(String::new());
+        if atom {
+            normalized.append_pair("format", "atom");
+        }
+        let pairs = [
+            ("limit", limit.map(|n| n.to_string())),
+            ("filter", filter),
+            ("filterout", filterout),
+            ("filter_title", filter_title),
+            ("filter_time", filter_time.map(|n| n.to_string())),
+            ("sort", sort.map(|_| "date".to_string())),
+            ("brief", brief.map(|n| n.to_string())),
+        ];
+        for (name, value) in pairs {
+            if let Some(value) = value {
+                normalized.append_pair(name, &value);
+            }
+        }
+
+        Ok(Self {
+            atom,
+            limit,
+            filter: compiled_filter,
+            filter_time,
+            sort,
+            brief,
+            normalized: normalized.finish(),
+        })
+    }
+
+    /// 是否输出 Atom
+    pub fn is_atom(&self) -> bool {
+        self.atom
+    }
+
+    /// 插件名加规范化参数组成的缓存键
+    pub fn cache_key(&self, plugin: &str) -> String {
+        format!("{}?{}", plugin, self.normalized)
+    }
+
+    /// 按查询参数调整 Feed 中的条目
+    pub fn apply(&self, feed: &mut RssFeed) {
+        let mut articles = std::mem::take(&mut feed.articles);
+
+        if let Some(ref filter) = self.filter {
+            articles = filter.apply(articles);
+        }
+
+        // 没有日期的条目无法判断新旧，保留
+        // 超出时间范围的值视为不限制
+        let since = self
+            .filter_time
+            .and_then(|seconds| Duration::try_seconds(i64::try_from(seconds).ok()?))
+            .and_then(|age| Utc::now().checked_sub_signed(age));
+        if let Some(since) = since {
+            articles.retain(|article| article.pub_date.is_none_or(|date| date >= since));
+        }
+
+        // 没有日期的条目留在原来的位置
+        if let Some(sort) = self.sort {
+            order::sort(&mut articles, sort);
+        }
+
+        if let Some(limit) = self.limit {
+            articles.truncate(limit);
+        }
+
+        if let Some(chars) = self.brief {
+            articles.iter_mut().for_each(|article| brief(article, chars));
+        }
+
+        feed.articles = articles;
+    }
+}
+
+/// 描述截断为前 `chars` 个字符，去掉全文内容
+fn brief(article: &mut Article, chars: usize) {
+    let text = article
+        .description
+        .take()
+        .or_else(|| article.content.as_deref().map(html_to_text))
+        .unwrap_or_default();
+
+    let mut summary: String = text.chars().take(chars).collect();
+    if text.chars().count() > chars {
+        summary.push('…');
+    }
+
+    article.description = Some(summary).filter(|s| !s.is_empty());
+    article.content = None;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, Utc};
+
+    fn article(title: &str, date: Option<DateTime<Utc>>) -> Article {
+        Article {
+            title: title.to_string(),
+            link: format!("https://example.com/{}", title),
+            description: Some(format!("About {}", title)),
+            content: Some(format!("<p>About {}</p>", title)),
+            pub_date: date,
+            ..Default::default()
+        }
+    }
+
+    fn feed(articles: Vec<Article>) -> RssFeed {
+        RssFeed {
+            title: "Test".to_string(),
+            description: "Test".to_string(),
+            link: "https://example.com".to_string(),
+            language: "en".to_string(),
+            articles,
+        }
+    }
+
+    fn titles(feed: &RssFeed) -> Vec<&str> {
+        feed.articles.iter().map(|a| a.title.as_str()).collect()
+    }
+
+    #[test]
+    fn test_filter_sort_limit_brief() {
+        let now = Utc::now();
+        let mut feed = feed(vec![
+            article("rust-old", Some(now - Duration::days(10))),
+            article("rust-new", Some(now - Duration::hours(1))),
+            article("rust-undated", None),
+            article("rust-sponsored", Some(now)),
+            article("go", Some(now)),
+        ]);
+
+        let query = FeedQuery::compile(&RssQuery {
+            filter: Some("RUST".to_string()),
+            filterout: Some("sponsored".to_string()),
+            filter_time: Some(86400),
+            sort: Some("date".to_string()),
+            limit: Some(2),
+            brief: Some(5),
+            ..Default::default()
+        })
+        .unwrap();
+        query.apply(&mut feed);
+
+        assert_eq!(titles(&feed), vec!["rust-new", "rust-undated"]);
+        assert_eq!(feed.articles[0].description.as_deref(), Some("About…"));
+        assert!(feed.articles[0].content.is_none());
+    }
+
+    #[test]
+    fn test_sort_keeps_undated_in_place() {
+        let now = Utc::now();
+        let mut feed = feed(vec![
+            article("old", Some(now - Duration::days(1))),
+            article("undated", None),
+            article("new", Some(now)),
+        ]);
+
+        let query = FeedQuery::compile(&RssQuery {
+            sort: Some("date".to_string()),
+            ..Default::default()
+        })
+        .unwrap();
+        query.apply(&mut feed);
+
+        assert_eq!(titles(&feed), vec!["new", "undated", "old"]);
+    }
+
+    #[test]
+    fn test_cache_key_is_normalized() {
+        let a = FeedQuery::compile(&RssQuery {
+            format: Some("rss".to_string()),
+            filter: Some(String::new()),
+            limit: Some(5),
+            ..Default::default()
+        })
+        .unwrap();
+        let b = FeedQuery::compile(&RssQuery {
+            limit: Some(5),
+            ..Default::default()
+        })
+        .unwrap();
+
+        assert_eq!(a.cache_key("blog"), b.cache_key("blog"));
+        assert_eq!(a.cache_key("blog"), "blog?limit=5");
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        let invalid_regex = RssQuery {
+            filter: Some("[".to_string()),
+            ..Default::default()
+        };
+        let invalid_sort = RssQuery {
+            sort: Some("title".to_string()),
+            ..Default::default()
+        };
+
+        assert!(FeedQuery::compile(&invalid_regex).is_err());
+        assert!(FeedQuery::compile(&invalid_sort).is_err());
+    }
+
+    #[test]
+    fn test_huge_filter_time_keeps_everything() {
+        let mut feed = feed(vec![article("old", Some(Utc::now() - Duration::days(3650)))]);
+
+        let query = FeedQuery::compile(&RssQuery {
+            filter_time: Some(u64::MAX),
+            ..Default::default()
+        })
+        .unwrap();
+        query.apply(&mut feed);
+
+        assert_eq!(titles(&feed), vec!["old"]);
+    }
+}
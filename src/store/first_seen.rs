@@ -0,0 +1,115 @@
+use crate::config::types::Article;
+use crate::error::Result;
+use crate::store::Store;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+/// 条目连续这么多天没有出现后删除记录
+const RETENTION_DAYS: i64 = 90;
+
+/// 条目第一次和最后一次出现的时间
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct SeenItem {
+    first_seen: DateTime<Utc>,
+    last_seen: DateTime<Utc>,
+}
+
+impl Store {
+    /// 为没有日期的文章填充第一次看到的时间
+    ///
+    /// 条目按 GUID（没有时按链接）区分。暂时从列表页消失的条目仍然保留记录，
+    /// 超过 90 天没有出现才删除。
+    pub fn fill_first_seen(&self, plugin: &str, articles: &mut [Article]) -> Result<()> {
+        self.fill_first_seen_at(plugin, articles, Utc::now())
+    }
+
+    fn fill_first_seen_at(&self, plugin: &str, articles: &mut [Article], now: DateTime<Utc>) -> Result<()> {
+        let tree = self.tree("first_seen", plugin)?;
+
+        for article in articles.iter_mut().filter(|article| article.pub_date.is_none()) {
+            let key = article.guid.clone().unwrap_or_else(|| article.link.clone());
+            let previous: Option<SeenItem> = match tree.get(&key)? {
+                Some(bytes) => serde_json::from_slice(&bytes).ok(),
+                None => None,
+            };
+
+            let item = SeenItem {
+                first_seen: previous.map_or(now, |item| item.first_seen),
+                last_seen: now,
+            };
+            article.pub_date = Some(item.first_seen);
+            tree.insert(key.as_bytes(), serde_json::to_vec(&item)?)?;
+        }
+
+        // 删除过期或无法反序列化的记录
+        let expires = now - Duration::days(RETENTION_DAYS);
+        for entry in tree.iter() {
+            let (key, value) = entry?;
+            let expired = serde_json::from_slice::<SeenItem>(&value).map_or(true, |item| item.last_seen < expires);
+            if expired {
+                tree.remove(key)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn article(link: &str) -> Article {
+        Article {
+            title: link.to_string(),
+            link: link.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_first_seen_survives_reopen() {
+        let path = std::env::temp_dir().join(format!("rsshub-first-seen-{}", std::process::id()));
+        let t0 = Utc::now();
+        let t1 = t0 + Duration::hours(1);
+
+        {
+            let store = Store::open(&path).unwrap();
+            let mut first = vec![article("/a")];
+            store.fill_first_seen_at("blog", &mut first, t0).unwrap();
+            assert_eq!(first[0].pub_date, Some(t0));
+        }
+
+        let store = Store::open(&path).unwrap();
+        // 条目消失一次后再出现，仍然使用第一次看到的时间
+        store.fill_first_seen_at("blog", &mut [article("/b")], t1).unwrap();
+        let mut again = vec![article("/b"), article("/a")];
+        store.fill_first_seen_at("blog", &mut again, t1 + Duration::hours(1)).unwrap();
+        assert_eq!(again[0].pub_date, Some(t1));
+        assert_eq!(again[1].pub_date, Some(t0));
+
+        // 其他插件互不影响
+        let mut other = vec![article("/a")];
+        store.fill_first_seen_at("other", &mut other, t1).unwrap();
+        assert_eq!(other[0].pub_date, Some(t1));
+
+        drop(store);
+        let _ = std::fs::remove_dir_all(&path);
+    }
+
+    #[test]
+    fn test_first_seen_expires() {
+        let store = Store::temporary().unwrap();
+        let t0 = Utc::now();
+
+        store.fill_first_seen_at("blog", &mut [article("/a")], t0).unwrap();
+        store
+            .fill_first_seen_at("blog", &mut [article("/b")], t0 + Duration::days(RETENTION_DAYS + 1))
+            .unwrap();
+
+        let later = t0 + Duration::days(RETENTION_DAYS + 2);
+        let mut again = vec![article("/a")];
+        store.fill_first_seen_at("blog", &mut again, later).unwrap();
+        assert_eq!(again[0].pub_date, Some(later));
+    }
+}
@@ -0,0 +1,155 @@
+use crate::config::types::{Article, HistoryConfig};
+use crate::error::Result;
+use crate::store::Store;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// 历史中保存的条目
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct StoredItem {
+    article: Article,
+    first_seen: DateTime<Utc>,
+    last_seen: DateTime<Utc>,
+    /// 最后一次出现时在页面中的位置，同一批条目按它排序
+    position: usize,
+}
+
+impl Store {
+    /// 把本次抓取的文章合并进插件的历史，返回按新旧排序的全部条目
+    ///
+    /// 条目按 GUID（没有时按链接）区分，重新出现的条目以本次抓取的内容为准。
+    /// 本次抓取的条目在前（保持页面顺序），之后是较早消失的条目。
+    /// `fill_first_seen` 为真时，没有日期的条目使用第一次看到的时间。
+    pub fn merge_history(
+        &self,
+        plugin: &str,
+        articles: Vec<Article>,
+        config: &HistoryConfig,
+        fill_first_seen: bool,
+    ) -> Result<Vec<Article>> {
+        self.merge_history_at(plugin, articles, config, fill_first_seen, Utc::now())
+    }
+
+    fn merge_history_at(
+        &self,
+        plugin: &str,
+        articles: Vec<Article>,
+        config: &HistoryConfig,
+        fill_first_seen: bool,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<Article>> {
+        let tree = self.tree("history", plugin)?;
+
+        let mut current = HashSet::new();
+        for (position, mut article) in articles.into_iter().enumerate() {
+            let key = article.guid.clone().unwrap_or_else(|| article.link.clone());
+            if !current.insert(key.clone()) {
+                continue;
+            }
+
+            let previous: Option<StoredItem> = match tree.get(&key)? {
+                Some(bytes) => serde_json::from_slice(&bytes).ok(),
+                None => None,
+            };
+            let first_seen = previous.map_or(now, |item| item.first_seen);
+
+            if fill_first_seen && article.pub_date.is_none() {
+                article.pub_date = Some(first_seen);
+            }
+
+            let item = StoredItem {
+                article,
+                first_seen,
+                last_seen: now,
+                position,
+            };
+            tree.insert(key.as_bytes(), serde_json::to_vec(&item)?)?;
+        }
+
+        // 无法反序列化的旧记录直接丢弃
+        let mut items = Vec::new();
+        for entry in tree.iter() {
+            let (key, value) = entry?;
+            match serde_json::from_slice::<StoredItem>(&value) {
+                Ok(item) => items.push((key, item)),
+                Err(_) => {
+                    tree.remove(key)?;
+                }
+            }
+        }
+
+        items.sort_by(|(_, a), (_, b)| b.last_seen.cmp(&a.last_seen).then(a.position.cmp(&b.position)));
+
+        // 按保留天数和数量淘汰
+        let expires = config.max_age_days.map(|days| now - Duration::days(days as i64));
+        let mut kept = Vec::new();
+        for (key, item) in items {
+            let expired = expires.is_some_and(|expires| item.last_seen < expires);
+            if expired || kept.len() >= config.max_items {
+                tree.remove(key)?;
+            } else {
+                kept.push(item.article);
+            }
+        }
+
+        Ok(kept)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn article(link: &str) -> Article {
+        Article {
+            title: link.to_string(),
+            link: link.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn links(articles: &[Article]) -> Vec<&str> {
+        articles.iter().map(|a| a.link.as_str()).collect()
+    }
+
+    #[test]
+    fn test_history_keeps_items_that_scrolled_off() {
+        let store = Store::temporary().unwrap();
+        let config = HistoryConfig::default();
+        let t0 = Utc::now();
+        let t1 = t0 + Duration::hours(1);
+
+        let first = vec![article("/b"), article("/a")];
+        store.merge_history_at("blog", first, &config, true, t0).unwrap();
+
+        let second = vec![article("/c"), article("/b")];
+        let merged = store.merge_history_at("blog", second, &config, true, t1).unwrap();
+
+        assert_eq!(links(&merged), vec!["/c", "/b", "/a"]);
+        // 第一次看到的时间不会被后续抓取覆盖
+        assert_eq!(merged[1].pub_date, Some(t0));
+        assert_eq!(merged[0].pub_date, Some(t1));
+    }
+
+    #[test]
+    fn test_history_retention() {
+        let store = Store::temporary().unwrap();
+        let config = HistoryConfig {
+            max_items: 2,
+            max_age_days: Some(7),
+        };
+        let t0 = Utc::now();
+
+        store.merge_history_at("blog", vec![article("/old")], &config, false, t0).unwrap();
+        let merged = store
+            .merge_history_at("blog", vec![article("/a"), article("/b")], &config, false, t0 + Duration::days(1))
+            .unwrap();
+        assert_eq!(links(&merged), vec!["/a", "/b"]);
+
+        let merged = store
+            .merge_history_at("blog", vec![article("/c")], &config, false, t0 + Duration::days(10))
+            .unwrap();
+        assert_eq!(links(&merged), vec!["/c"]);
+    }
+}
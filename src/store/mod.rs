@@ -0,0 +1,32 @@
+pub mod first_seen;
+pub mod history;
+pub mod monitor;
+
+use crate::error::Result;
+use std::path::Path;
+
+/// 基于 sled 的嵌入式持久化存储
+///
+/// 每个插件的数据保存在独立的 tree 中，进程内共享一个实例。
+#[derive(Debug, Clone)]
+pub struct Store {
+    db: sled::Db,
+}
+
+impl Store {
+    /// 打开（必要时创建）数据目录下的数据库
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        Ok(Self { db: sled::open(path)? })
+    }
+
+    /// 临时数据库，关闭后自动删除，用于测试
+    pub fn temporary() -> Result<Self> {
+        Ok(Self {
+            db: sled::Config::new().temporary(true).open()?,
+        })
+    }
+
+    fn tree(&self, kind: &str, plugin: &str) -> Result<sled::Tree> {
+        Ok(self.db.open_tree(format!("{}:{}", kind, plugin))?)
+    }
+}
@@ -0,0 +1,103 @@
+use crate::error::Result;
+use crate::parser::monitor::diff_snapshots;
+use crate::store::Store;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+const SNAPSHOT_KEY: &[u8] = b"current";
+
+/// 页面的一次变化
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PageChange {
+    pub detected_at: DateTime<Utc>,
+    /// 与上一次快照的差异（HTML）
+    pub diff: String,
+    pub added: usize,
+    pub removed: usize,
+}
+
+impl Store {
+    /// 保存插件的页面快照，与上一次快照不同时记录一次变化
+    ///
+    /// 返回保留的全部变化，从新到旧；第一次保存快照时没有变化。
+    pub fn record_snapshot(&self, plugin: &str, snapshot: &str, max_changes: usize) -> Result<Vec<PageChange>> {
+        self.record_snapshot_at(plugin, snapshot, max_changes, Utc::now())
+    }
+
+    fn record_snapshot_at(
+        &self,
+        plugin: &str,
+        snapshot: &str,
+        max_changes: usize,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<PageChange>> {
+        let snapshots = self.tree("snapshot", plugin)?;
+        let changes = self.tree("changes", plugin)?;
+
+        // insert 原子地返回旧值，并发请求不会重复记录同一次变化
+        let previous = snapshots.insert(SNAPSHOT_KEY, snapshot.as_bytes())?;
+        if let Some(previous) = previous {
+            let previous = String::from_utf8_lossy(&previous);
+            if previous != snapshot {
+                let diff = diff_snapshots(&previous, snapshot);
+                let change = PageChange {
+                    detected_at: now,
+                    diff: diff.html,
+                    added: diff.added,
+                    removed: diff.removed,
+                };
+                // 大端序的时间戳使 tree 按时间排序
+                let key = (now.timestamp_micros().max(0) as u64).to_be_bytes();
+                changes.insert(key, serde_json::to_vec(&change)?)?;
+            }
+        }
+
+        // 从新到旧，超出数量或无法反序列化的记录直接删除
+        let mut kept = Vec::new();
+        for entry in changes.iter().rev() {
+            let (key, value) = entry?;
+            match serde_json::from_slice::<PageChange>(&value) {
+                Ok(change) if kept.len() < max_changes => kept.push(change),
+                _ => {
+                    changes.remove(key)?;
+                }
+            }
+        }
+
+        Ok(kept)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_record_snapshot_changes() {
+        let store = Store::temporary().unwrap();
+        let t0 = Utc::now();
+
+        // 第一次只保存快照
+        assert!(store.record_snapshot_at("pricing", "Pro\n$10", 2, t0).unwrap().is_empty());
+        // 内容不变不产生变化
+        assert!(store
+            .record_snapshot_at("pricing", "Pro\n$10", 2, t0 + Duration::hours(1))
+            .unwrap()
+            .is_empty());
+
+        let changes = store
+            .record_snapshot_at("pricing", "Pro\n$12", 2, t0 + Duration::hours(2))
+            .unwrap();
+        assert_eq!(changes.len(), 1);
+        assert_eq!((changes[0].added, changes[0].removed), (1, 1));
+        assert_eq!(changes[0].detected_at, t0 + Duration::hours(2));
+
+        store.record_snapshot_at("pricing", "Pro\n$15", 2, t0 + Duration::hours(3)).unwrap();
+        let changes = store
+            .record_snapshot_at("pricing", "Pro\n$20", 2, t0 + Duration::hours(4))
+            .unwrap();
+        let times: Vec<_> = changes.iter().map(|c| c.detected_at).collect();
+        assert_eq!(times, vec![t0 + Duration::hours(4), t0 + Duration::hours(3)]);
+    }
+}
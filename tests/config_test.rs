@@ -1,4 +1,4 @@
-use rust_rsshub::{PluginConfig, SourceConfig, CacheConfig};
+use rust_rsshub::{Article, GuidConfig, GuidField, PluginConfig, SourceConfig, CacheConfig};
 
 #[test]
 fn test_cache_config_default() {
@@ -186,3 +186,166 @@ feed:
     assert_eq!(config.feed.format, "rss");
     assert_eq!(config.feed.limit, 20);
 }
+
+#[test]
+fn test_field_selector_forms() {
+    let yaml = r#"
+plugin:
+  name: "test"
+  description: "Test"
+
+source:
+  type: "html"
+  url: "https://example.com"
+
+parser:
+  list:
+    selector: "div.post"
+    link_selector: "a"
+    title_selector: ["h1.featured a", "h2 a"]
+    author_selector:
+      selector: "span.author"
+      required: true
+    category_selector:
+      selector: ["span.tag", "span.category"]
+      default: "General"
+
+feed:
+  title: "Test Feed"
+  description: "Test Description"
+  link: "https://example.com"
+"#;
+
+    let config: PluginConfig = serde_yaml::from_str(yaml).unwrap();
+    let list = &config.parser.list;
+
+    assert_eq!(list.link_selector.selectors, vec!["a"]);
+    assert!(!list.link_selector.required);
+    assert_eq!(list.title_selector.selectors, vec!["h1.featured a", "h2 a"]);
+
+    let author = list.author_selector.as_ref().unwrap();
+    assert_eq!(author.selectors, vec!["span.author"]);
+    assert!(author.required);
+
+    let category = list.category_selector.as_ref().unwrap();
+    assert_eq!(category.selectors, vec!["span.tag", "span.category"]);
+    assert_eq!(category.default, Some("General".to_string()));
+}
+
+#[test]
+fn test_article_json_category_compatibility() {
+    // 旧版 JSON 只有单个 category 字段
+    let old_json = r#"{"title":"A","link":"https://example.com/a","category":"Tech"}"#;
+    let article: Article = serde_json::from_str(old_json).unwrap();
+    assert_eq!(article.categories, vec!["Tech"]);
+
+    // 新版 JSON 往返
+    let json = serde_json::to_string(&article).unwrap();
+    let parsed: Article = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed.categories, vec!["Tech"]);
+
+    // 旧版读取方仍能看到第一个分类
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(value["category"], "Tech");
+
+    // 同时带有两个字段时合并，不会报重复字段
+    let json = r#"{"title":"A","link":"https://example.com/a","category":"Tech","categories":["Rust","Tech"]}"#;
+    let article: Article = serde_json::from_str(json).unwrap();
+    assert_eq!(article.categories, vec!["Rust", "Tech"]);
+
+    let json = r#"{"title":"A","link":"https://example.com/a","category":"News","categories":["Rust"]}"#;
+    let article: Article = serde_json::from_str(json).unwrap();
+    assert_eq!(article.categories, vec!["News", "Rust"]);
+
+    // 缺少分类字段
+    let json = r#"{"title":"A","link":"https://example.com/a","category":null}"#;
+    let article: Article = serde_json::from_str(json).unwrap();
+    assert!(article.categories.is_empty());
+}
+
+#[test]
+fn test_guid_config_forms() {
+    let parse = |guid: &str| -> Option<GuidConfig> {
+        let yaml = format!(
+            r#"
+plugin:
+  name: "test"
+  description: "Test"
+
+source:
+  type: "html"
+  url: "https://example.com"
+
+parser:
+  list:
+    selector: "div.post"
+    link_selector: "a"
+    title_selector: "a"
+{}
+
+feed:
+  title: "Test Feed"
+  description: "Test Description"
+  link: "https://example.com"
+"#,
+            guid
+        );
+        let config: PluginConfig = serde_yaml::from_str(&yaml).unwrap();
+        config.parser.guid
+    };
+
+    assert_eq!(parse(""), None);
+    assert_eq!(
+        parse("  guid:\n    type: selector\n    selector: \"a\"\n    attr: \"data-id\""),
+        Some(GuidConfig::Selector {
+            selector: vec!["a".to_string()],
+            attr: Some("data-id".to_string()),
+        })
+    );
+    assert_eq!(
+        parse("  guid:\n    type: link\n    strip_params: [\"utm_*\"]"),
+        Some(GuidConfig::Link {
+            strip_params: vec!["utm_*".to_string()],
+        })
+    );
+    // 哈希默认使用标题和链接
+    assert_eq!(
+        parse("  guid:\n    type: hash"),
+        Some(GuidConfig::Hash {
+            fields: vec![GuidField::Title, GuidField::Link],
+        })
+    );
+}
+
+#[test]
+fn test_history_config() {
+    let yaml = r#"
+plugin:
+  name: "test"
+  description: "Test"
+
+source:
+  type: "html"
+  url: "https://example.com"
+
+parser:
+  list:
+    selector: "div.post"
+    link_selector: "a"
+    title_selector: "a"
+
+feed:
+  title: "Test Feed"
+  description: "Test Description"
+  link: "https://example.com"
+
+history:
+  max_age_days: 30
+"#;
+
+    let config: PluginConfig = serde_yaml::from_str(yaml).unwrap();
+    let history = config.history.unwrap();
+
+    assert_eq!(history.max_items, 200);
+    assert_eq!(history.max_age_days, Some(30));
+}
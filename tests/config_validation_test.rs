@@ -1,4 +1,4 @@
-use rust_rsshub::{PluginConfig, SourceConfig};
+use rust_rsshub::{FieldSelector, PluginConfig, SourceConfig};
 
 #[test]
 fn test_invalid_feed_title() {
@@ -347,11 +347,11 @@ feed:
     let list_parser = &config.parser.list;
     assert_eq!(list_parser.selector, "div.post");
     assert_eq!(list_parser.item_selector, Some("div.post-item".to_string()));
-    assert_eq!(list_parser.link_selector, "a.post-link");
-    assert_eq!(list_parser.title_selector, "h2.title");
-    assert_eq!(list_parser.description_selector, Some("p.excerpt".to_string()));
-    assert_eq!(list_parser.date_selector, Some("time.published".to_string()));
+    assert_eq!(list_parser.link_selector, FieldSelector::from("a.post-link"));
+    assert_eq!(list_parser.title_selector, FieldSelector::from("h2.title"));
+    assert_eq!(list_parser.description_selector, Some(FieldSelector::from("p.excerpt")));
+    assert_eq!(list_parser.date_selector, Some(FieldSelector::from("time.published")));
     assert_eq!(list_parser.date_format, Some("%Y-%m-%d %H:%M:%S".to_string()));
-    assert_eq!(list_parser.author_selector, Some("span.author-name".to_string()));
-    assert_eq!(list_parser.category_selector, Some("span.category-tag".to_string()));
+    assert_eq!(list_parser.author_selector, Some(FieldSelector::from("span.author-name")));
+    assert_eq!(list_parser.category_selector, Some(FieldSelector::from("span.category-tag")));
 }
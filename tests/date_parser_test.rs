@@ -18,16 +18,13 @@ fn test_parse_with_date_iso() {
     let config = ParserConfig {
         list: ListParserConfig {
             selector: ".post".to_string(),
-            item_selector: None,
-            link_selector: ".link".to_string(),
-            title_selector: ".title".to_string(),
-            description_selector: None,
-            date_selector: Some(".date".to_string()),
+            link_selector: ".link".into(),
+            title_selector: ".title".into(),
+            date_selector: Some(".date".into()),
             date_format: Some("%Y-%m-%d".to_string()),
-            author_selector: None,
-            category_selector: None,
+            ..Default::default()
         },
-        content: None,
+        ..Default::default()
     };
 
     let articles = HtmlParser::parse(html, &config, "https://example.com", 20).unwrap();
@@ -53,16 +50,12 @@ fn test_parse_with_date_rfc3339() {
     let config = ParserConfig {
         list: ListParserConfig {
             selector: ".post".to_string(),
-            item_selector: None,
-            link_selector: ".link".to_string(),
-            title_selector: ".title".to_string(),
-            description_selector: None,
-            date_selector: Some(".date".to_string()),
-            date_format: None,
-            author_selector: None,
-            category_selector: None,
+            link_selector: ".link".into(),
+            title_selector: ".title".into(),
+            date_selector: Some(".date".into()),
+            ..Default::default()
         },
-        content: None,
+        ..Default::default()
     };
 
     let articles = HtmlParser::parse(html, &config, "https://example.com", 20).unwrap();
@@ -88,16 +81,12 @@ fn test_parse_with_date_month_name() {
     let config = ParserConfig {
         list: ListParserConfig {
             selector: ".post".to_string(),
-            item_selector: None,
-            link_selector: ".link".to_string(),
-            title_selector: ".title".to_string(),
-            description_selector: None,
-            date_selector: Some(".date".to_string()),
-            date_format: None,
-            author_selector: None,
-            category_selector: None,
+            link_selector: ".link".into(),
+            title_selector: ".title".into(),
+            date_selector: Some(".date".into()),
+            ..Default::default()
         },
-        content: None,
+        ..Default::default()
     };
 
     let articles = HtmlParser::parse(html, &config, "https://example.com", 20).unwrap();
@@ -133,16 +122,12 @@ fn test_parse_with_multiple_dates() {
     let config = ParserConfig {
         list: ListParserConfig {
             selector: ".post".to_string(),
-            item_selector: None,
-            link_selector: ".link".to_string(),
-            title_selector: ".title".to_string(),
-            description_selector: None,
-            date_selector: Some(".date".to_string()),
-            date_format: None,
-            author_selector: None,
-            category_selector: None,
+            link_selector: ".link".into(),
+            title_selector: ".title".into(),
+            date_selector: Some(".date".into()),
+            ..Default::default()
         },
-        content: None,
+        ..Default::default()
     };
 
     let articles = HtmlParser::parse(html, &config, "https://example.com", 20).unwrap();
@@ -171,16 +156,12 @@ fn test_parse_with_invalid_date() {
     let config = ParserConfig {
         list: ListParserConfig {
             selector: ".post".to_string(),
-            item_selector: None,
-            link_selector: ".link".to_string(),
-            title_selector: ".title".to_string(),
-            description_selector: None,
-            date_selector: Some(".date".to_string()),
-            date_format: None,
-            author_selector: None,
-            category_selector: None,
+            link_selector: ".link".into(),
+            title_selector: ".title".into(),
+            date_selector: Some(".date".into()),
+            ..Default::default()
         },
-        content: None,
+        ..Default::default()
     };
 
     let articles = HtmlParser::parse(html, &config, "https://example.com", 20).unwrap();
@@ -207,16 +188,12 @@ fn test_parse_with_empty_date() {
     let config = ParserConfig {
         list: ListParserConfig {
             selector: ".post".to_string(),
-            item_selector: None,
-            link_selector: ".link".to_string(),
-            title_selector: ".title".to_string(),
-            description_selector: None,
-            date_selector: Some(".date".to_string()),
-            date_format: None,
-            author_selector: None,
-            category_selector: None,
+            link_selector: ".link".into(),
+            title_selector: ".title".into(),
+            date_selector: Some(".date".into()),
+            ..Default::default()
         },
-        content: None,
+        ..Default::default()
     };
 
     let articles = HtmlParser::parse(html, &config, "https://example.com", 20).unwrap();
@@ -242,16 +219,11 @@ fn test_parse_without_date_field() {
     let config = ParserConfig {
         list: ListParserConfig {
             selector: ".post".to_string(),
-            item_selector: None,
-            link_selector: ".link".to_string(),
-            title_selector: ".title".to_string(),
-            description_selector: None,
-            date_selector: None,
-            date_format: None,
-            author_selector: None,
-            category_selector: None,
+            link_selector: ".link".into(),
+            title_selector: ".title".into(),
+            ..Default::default()
         },
-        content: None,
+        ..Default::default()
     };
 
     let articles = HtmlParser::parse(html, &config, "https://example.com", 20).unwrap();
@@ -260,3 +232,34 @@ fn test_parse_without_date_field() {
     // 没有日期字段
     assert!(articles[0].pub_date.is_none());
 }
+
+#[test]
+fn test_parse_with_date_rfc3339_offset() {
+    let html = r#"
+    <html>
+        <body>
+            <div class="post">
+                <h2 class="title">Article</h2>
+                <a class="link" href="/article">Link</a>
+                <time class="date">2024-01-15T10:30:00+08:00</time>
+            </div>
+        </body>
+    </html>
+    "#;
+
+    let config = ParserConfig {
+        list: ListParserConfig {
+            selector: ".post".to_string(),
+            link_selector: ".link".into(),
+            title_selector: ".title".into(),
+            date_selector: Some(".date".into()),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let articles = HtmlParser::parse(html, &config, "https://example.com", 20).unwrap();
+
+    // 带时区的时间按时区换算为 UTC
+    assert_eq!(articles[0].pub_date.unwrap().to_rfc3339(), "2024-01-15T02:30:00+00:00");
+}
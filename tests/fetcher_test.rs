@@ -29,6 +29,7 @@ async fn test_fetch_html_with_example_com() {
         encoding: "utf-8".to_string(),
         user_agent: None,
         request: RequestConfig::default(),
+        embedded: None,
     });
 
     let result = fetcher.fetch_html(&source).await;
@@ -65,6 +66,7 @@ async fn test_fetch_html_with_custom_headers() {
             headers,
             timeout: 30,
         },
+        embedded: None,
     });
 
     let result = fetcher.fetch_html(&source).await;
@@ -88,6 +90,7 @@ async fn test_fetch_html_with_invalid_url() {
         encoding: "utf-8".to_string(),
         user_agent: None,
         request: RequestConfig::default(),
+        embedded: None,
     });
 
     let result = fetcher.fetch_html(&source).await;
@@ -127,6 +130,7 @@ async fn test_fetch_html_timeout() {
             headers: HashMap::new(),
             timeout: 1, // 1秒超时
         },
+        embedded: None,
     });
 
     // 这个测试通常会成功，因为 example.com 响应很快
@@ -153,6 +157,7 @@ async fn test_different_encodings() {
         encoding: "utf-8".to_string(),
         user_agent: None,
         request: RequestConfig::default(),
+        embedded: None,
     });
 
     let result = fetcher.fetch_html(&utf8_source).await;
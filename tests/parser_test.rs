@@ -1,6 +1,7 @@
-use rust_rsshub::parser::HtmlParser;
-use rust_rsshub::ParserConfig;
+use rust_rsshub::parser::{CompiledParser, HtmlParser};
+use rust_rsshub::{Article, Enrichment, ParserConfig, ParserMode, PluginConfig};
 use rust_rsshub::ListParserConfig;
+use rust_rsshub::{Enclosure, FieldSelector, GuidConfig, GuidField, ParseBudget, RssHubError, Transform};
 
 #[test]
 fn test_parse_simple_html() {
@@ -28,16 +29,15 @@ fn test_parse_simple_html() {
     let config = ParserConfig {
         list: ListParserConfig {
             selector: ".post".to_string(),
-            item_selector: None,
-            link_selector: ".link".to_string(),
-            title_selector: ".title".to_string(),
-            description_selector: Some(".desc".to_string()),
-            date_selector: Some(".date".to_string()),
+            link_selector: ".link".into(),
+            title_selector: ".title".into(),
+            description_selector: Some(".desc".into()),
+            date_selector: Some(".date".into()),
             date_format: Some("%Y-%m-%d".to_string()),
-            author_selector: Some(".author".to_string()),
-            category_selector: None,
+            author_selector: Some(".author".into()),
+            ..Default::default()
         },
-        content: None,
+        ..Default::default()
     };
 
     let articles = HtmlParser::parse(html, &config, "https://example.com", 20).unwrap();
@@ -82,16 +82,11 @@ fn test_parse_with_limit() {
     let config = ParserConfig {
         list: ListParserConfig {
             selector: ".post".to_string(),
-            item_selector: None,
-            link_selector: ".link".to_string(),
-            title_selector: ".title".to_string(),
-            description_selector: None,
-            date_selector: None,
-            date_format: None,
-            author_selector: None,
-            category_selector: None,
+            link_selector: ".link".into(),
+            title_selector: ".title".into(),
+            ..Default::default()
         },
-        content: None,
+        ..Default::default()
     };
 
     let articles = HtmlParser::parse(html, &config, "https://example.com", 20).unwrap();
@@ -120,16 +115,11 @@ fn test_parse_skip_empty_title() {
     let config = ParserConfig {
         list: ListParserConfig {
             selector: ".post".to_string(),
-            item_selector: None,
-            link_selector: ".link".to_string(),
-            title_selector: ".title".to_string(),
-            description_selector: None,
-            date_selector: None,
-            date_format: None,
-            author_selector: None,
-            category_selector: None,
+            link_selector: ".link".into(),
+            title_selector: ".title".into(),
+            ..Default::default()
         },
-        content: None,
+        ..Default::default()
     };
 
     let articles = HtmlParser::parse(html, &config, "https://example.com", 20).unwrap();
@@ -155,16 +145,11 @@ fn test_parse_with_absolute_url() {
     let config = ParserConfig {
         list: ListParserConfig {
             selector: ".post".to_string(),
-            item_selector: None,
-            link_selector: ".link".to_string(),
-            title_selector: ".title".to_string(),
-            description_selector: None,
-            date_selector: None,
-            date_format: None,
-            author_selector: None,
-            category_selector: None,
+            link_selector: ".link".into(),
+            title_selector: ".title".into(),
+            ..Default::default()
         },
-        content: None,
+        ..Default::default()
     };
 
     let articles = HtmlParser::parse(html, &config, "https://example.com", 20).unwrap();
@@ -190,16 +175,11 @@ fn test_parse_with_relative_url() {
     let config = ParserConfig {
         list: ListParserConfig {
             selector: ".post".to_string(),
-            item_selector: None,
-            link_selector: ".link".to_string(),
-            title_selector: ".title".to_string(),
-            description_selector: None,
-            date_selector: None,
-            date_format: None,
-            author_selector: None,
-            category_selector: None,
+            link_selector: ".link".into(),
+            title_selector: ".title".into(),
+            ..Default::default()
         },
-        content: None,
+        ..Default::default()
     };
 
     let articles = HtmlParser::parse(html, &config, "https://example.com", 20).unwrap();
@@ -229,16 +209,11 @@ fn test_parse_with_nested_selector() {
     let config = ParserConfig {
         list: ListParserConfig {
             selector: ".post".to_string(),
-            item_selector: None,
-            link_selector: ".link".to_string(),
-            title_selector: ".title".to_string(),
-            description_selector: None,
-            date_selector: None,
-            date_format: None,
-            author_selector: None,
-            category_selector: None,
+            link_selector: ".link".into(),
+            title_selector: ".title".into(),
+            ..Default::default()
         },
-        content: None,
+        ..Default::default()
     };
 
     let articles = HtmlParser::parse(html, &config, "https://example.com", 20).unwrap();
@@ -265,22 +240,18 @@ fn test_parse_with_category() {
     let config = ParserConfig {
         list: ListParserConfig {
             selector: ".post".to_string(),
-            item_selector: None,
-            link_selector: ".link".to_string(),
-            title_selector: ".title".to_string(),
-            description_selector: None,
-            date_selector: None,
-            date_format: None,
-            author_selector: None,
-            category_selector: Some(".category".to_string()),
+            link_selector: ".link".into(),
+            title_selector: ".title".into(),
+            category_selector: Some(".category".into()),
+            ..Default::default()
         },
-        content: None,
+        ..Default::default()
     };
 
     let articles = HtmlParser::parse(html, &config, "https://example.com", 20).unwrap();
 
     assert_eq!(articles.len(), 1);
-    assert_eq!(articles[0].category, Some("Technology".to_string()));
+    assert_eq!(articles[0].categories, vec!["Technology".to_string()]);
 }
 
 #[test]
@@ -299,16 +270,11 @@ fn test_parse_with_missing_optional_fields() {
     let config = ParserConfig {
         list: ListParserConfig {
             selector: ".post".to_string(),
-            item_selector: None,
-            link_selector: ".link".to_string(),
-            title_selector: ".title".to_string(),
-            description_selector: None,
-            date_selector: None,
-            date_format: None,
-            author_selector: None,
-            category_selector: None,
+            link_selector: ".link".into(),
+            title_selector: ".title".into(),
+            ..Default::default()
         },
-        content: None,
+        ..Default::default()
     };
 
     let articles = HtmlParser::parse(html, &config, "https://example.com", 20).unwrap();
@@ -318,5 +284,994 @@ fn test_parse_with_missing_optional_fields() {
     assert!(articles[0].description.is_none());
     assert!(articles[0].pub_date.is_none());
     assert!(articles[0].author.is_none());
-    assert!(articles[0].category.is_none());
+    assert!(articles[0].categories.is_empty());
+}
+
+#[test]
+fn test_parse_with_selector_fallback_chain() {
+    let html = r#"
+    <html>
+        <body>
+            <div class="post featured">
+                <h1 class="featured-title">Featured Article</h1>
+                <a class="link" href="/featured">Link</a>
+                <span class="featured-date">2024-02-01</span>
+            </div>
+            <div class="post">
+                <h2 class="title">Regular Article</h2>
+                <a class="link" href="/regular">Link</a>
+                <time class="date">2024-01-15</time>
+            </div>
+        </body>
+    </html>
+    "#;
+
+    let config = ParserConfig {
+        list: ListParserConfig {
+            selector: ".post".to_string(),
+            link_selector: ".link".into(),
+            title_selector: vec![".featured-title".to_string(), ".title".to_string()].into(),
+            date_selector: Some(vec![".featured-date".to_string(), ".date".to_string()].into()),
+            date_format: Some("%Y-%m-%d".to_string()),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let articles = HtmlParser::parse(html, &config, "https://example.com", 20).unwrap();
+
+    assert_eq!(articles.len(), 2);
+    assert_eq!(articles[0].title, "Featured Article");
+    assert_eq!(articles[1].title, "Regular Article");
+    assert!(articles[0].pub_date.is_some());
+    assert!(articles[1].pub_date.is_some());
+}
+
+#[test]
+fn test_parse_skip_missing_required_field() {
+    let html = r#"
+    <html>
+        <body>
+            <div class="post">
+                <h2 class="title">With Author</h2>
+                <a class="link" href="/1">Link</a>
+                <span class="author">Alice</span>
+            </div>
+            <div class="post">
+                <h2 class="title">Without Author</h2>
+                <a class="link" href="/2">Link</a>
+            </div>
+        </body>
+    </html>
+    "#;
+
+    let config = ParserConfig {
+        list: ListParserConfig {
+            selector: ".post".to_string(),
+            link_selector: ".link".into(),
+            title_selector: ".title".into(),
+            author_selector: Some(FieldSelector {
+                required: true,
+                ..FieldSelector::new(".author")
+            }),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let articles = HtmlParser::parse(html, &config, "https://example.com", 20).unwrap();
+
+    // 缺少必需字段的文章应该被跳过
+    assert_eq!(articles.len(), 1);
+    assert_eq!(articles[0].title, "With Author");
+}
+
+#[test]
+fn test_parse_with_field_default() {
+    let html = r#"
+    <html>
+        <body>
+            <div class="post">
+                <h2 class="title">Article</h2>
+                <a class="link" href="/article">Link</a>
+            </div>
+        </body>
+    </html>
+    "#;
+
+    let config = ParserConfig {
+        list: ListParserConfig {
+            selector: ".post".to_string(),
+            link_selector: ".link".into(),
+            title_selector: ".title".into(),
+            author_selector: Some(FieldSelector {
+                default: Some("Anonymous".to_string()),
+                ..FieldSelector::new(".author")
+            }),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let articles = HtmlParser::parse(html, &config, "https://example.com", 20).unwrap();
+
+    assert_eq!(articles.len(), 1);
+    assert_eq!(articles[0].author, Some("Anonymous".to_string()));
+}
+
+#[test]
+fn test_parse_with_field_transforms() {
+    let html = r#"
+    <html>
+        <body>
+            <div class="post">
+                <h2 class="title">Article</h2>
+                <a class="link" href="/article">Link</a>
+                <span class="byline">Posted by Alice</span>
+                <span class="meta">Published on 2024-01-01 | 5 min read</span>
+            </div>
+        </body>
+    </html>
+    "#;
+
+    let config = ParserConfig {
+        list: ListParserConfig {
+            selector: ".post".to_string(),
+            link_selector: ".link".into(),
+            title_selector: ".title".into(),
+            date_selector: Some(FieldSelector {
+                transforms: vec![Transform::Regex {
+                    pattern: r"Published on (\d{4}-\d{2}-\d{2})".to_string(),
+                    group: None,
+                }],
+                ..FieldSelector::new(".meta")
+            }),
+            date_format: Some("%Y-%m-%d".to_string()),
+            author_selector: Some(FieldSelector {
+                transforms: vec![Transform::Replace {
+                    pattern: r"^Posted by\s+".to_string(),
+                    replacement: String::new(),
+                }],
+                ..FieldSelector::new(".byline")
+            }),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let articles = HtmlParser::parse(html, &config, "https://example.com", 20).unwrap();
+
+    assert_eq!(articles.len(), 1);
+    assert_eq!(articles[0].author, Some("Alice".to_string()));
+    assert_eq!(
+        articles[0].pub_date.unwrap().format("%Y-%m-%d").to_string(),
+        "2024-01-01"
+    );
+}
+
+#[test]
+fn test_parse_budget_size_exceeded() {
+    let html = r#"
+    <html>
+        <body>
+            <div class="post">
+                <h2 class="title">Article</h2>
+                <a class="link" href="/article">Link</a>
+            </div>
+        </body>
+    </html>
+    "#;
+
+    let config = ParserConfig {
+        list: ListParserConfig {
+            selector: ".post".to_string(),
+            link_selector: ".link".into(),
+            title_selector: ".title".into(),
+            ..Default::default()
+        },
+        budget: ParseBudget {
+            max_bytes: 16,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let result = HtmlParser::parse(html, &config, "https://example.com", 20);
+
+    assert!(matches!(result, Err(RssHubError::ParseError(ref msg)) if msg.contains("Parse budget exceeded")));
+}
+
+#[test]
+fn test_parse_budget_time_exceeded() {
+    let mut html = String::from("<html><body>");
+    for i in 0..2000 {
+        html.push_str(&format!(
+            r#"<div class="post"><h2 class="title">Article {}</h2><a class="link" href="/{}">Link</a></div>"#,
+            i, i
+        ));
+    }
+    html.push_str("</body></html>");
+
+    let config = ParserConfig {
+        list: ListParserConfig {
+            selector: ".post".to_string(),
+            link_selector: ".link".into(),
+            title_selector: ".title".into(),
+            ..Default::default()
+        },
+        budget: ParseBudget {
+            max_duration_ms: 0,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let result = HtmlParser::parse(&html, &config, "https://example.com", 5000);
+
+    assert!(matches!(result, Err(RssHubError::ParseError(ref msg)) if msg.contains("Parse budget exceeded")));
+}
+
+#[test]
+fn test_parse_description_html_mode() {
+    let html = r#"
+    <html>
+        <body>
+            <div class="post">
+                <h2 class="title">Article</h2>
+                <a class="link" href="/blog/article">Link</a>
+                <div class="desc">
+                    <p onclick="track()">Intro <a href="/blog/more">more</a></p>
+                    <img src="images/cover.png" alt="Cover">
+                    <script>alert(1)</script>
+                </div>
+            </div>
+        </body>
+    </html>
+    "#;
+
+    let config = ParserConfig {
+        list: ListParserConfig {
+            selector: ".post".to_string(),
+            link_selector: ".link".into(),
+            title_selector: ".title".into(),
+            description_selector: Some(FieldSelector {
+                html: true,
+                ..FieldSelector::new(".desc")
+            }),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let articles = HtmlParser::parse(html, &config, "https://example.com/blog/", 20).unwrap();
+
+    assert_eq!(articles.len(), 1);
+
+    // 标记保留在内容中，并经过清理和绝对化
+    let content = articles[0].content.as_ref().unwrap();
+    assert!(content.contains(r#"<a href="https://example.com/blog/more""#));
+    assert!(content.contains(r#"src="https://example.com/blog/images/cover.png""#));
+    assert!(!content.contains("script"));
+    assert!(!content.contains("onclick"));
+
+    // 描述是纯文本
+    assert_eq!(articles[0].description, Some("Intro more".to_string()));
+}
+
+#[test]
+fn test_selector_without_item_selector_treats_container_as_item() {
+    let html = r#"
+    <html>
+        <body>
+            <section class="list">
+                <div class="item"><h2 class="title">A</h2><a class="link" href="/a">Link</a></div>
+                <div class="item"><h2 class="title">B</h2><a class="link" href="/b">Link</a></div>
+            </section>
+        </body>
+    </html>
+    "#;
+
+    let config = ParserConfig {
+        list: ListParserConfig {
+            selector: "section.list".to_string(),
+            link_selector: ".link".into(),
+            title_selector: ".title".into(),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let articles = HtmlParser::parse(html, &config, "https://example.com", 20).unwrap();
+
+    // 没有 item_selector 时，整个容器只产生一篇文章（取第一个匹配）
+    assert_eq!(articles.len(), 1);
+    assert_eq!(articles[0].title, "A");
+}
+
+#[test]
+fn test_item_selector_scopes_items_inside_containers() {
+    let html = r#"
+    <html>
+        <body>
+            <section class="list">
+                <div class="item"><h2 class="title">A</h2><a class="link" href="/a">Link</a></div>
+                <div class="item"><h2 class="title">B</h2><a class="link" href="/b">Link</a></div>
+            </section>
+            <aside>
+                <div class="item"><h2 class="title">Sidebar</h2><a class="link" href="/s">Link</a></div>
+            </aside>
+            <section class="list">
+                <div class="item"><h2 class="title">C</h2><a class="link" href="/c">Link</a></div>
+            </section>
+        </body>
+    </html>
+    "#;
+
+    let config = ParserConfig {
+        list: ListParserConfig {
+            selector: "section.list".to_string(),
+            item_selector: Some("div.item".to_string()),
+            link_selector: ".link".into(),
+            title_selector: ".title".into(),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let articles = HtmlParser::parse(html, &config, "https://example.com", 20).unwrap();
+
+    // 只选取容器内的条目，侧边栏中的条目被排除
+    let titles: Vec<_> = articles.iter().map(|a| a.title.as_str()).collect();
+    assert_eq!(titles, vec!["A", "B", "C"]);
+}
+
+#[test]
+fn test_parse_with_multiple_categories() {
+    let html = r#"
+    <html>
+        <body>
+            <div class="post">
+                <h2 class="title">Tagged</h2>
+                <a class="link" href="/tagged">Link</a>
+                <span class="tag">Rust</span>
+                <span class="tag">Web</span>
+                <span class="tag">Rust</span>
+            </div>
+            <div class="post">
+                <h2 class="title">Delimited</h2>
+                <a class="link" href="/delimited">Link</a>
+                <span class="tags">Rust, Async , Tokio</span>
+            </div>
+        </body>
+    </html>
+    "#;
+
+    let config = ParserConfig {
+        list: ListParserConfig {
+            selector: ".post".to_string(),
+            link_selector: ".link".into(),
+            title_selector: ".title".into(),
+            category_selector: Some(vec![".tag".to_string(), ".tags".to_string()].into()),
+            category_delimiter: Some(",".to_string()),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let articles = HtmlParser::parse(html, &config, "https://example.com", 20).unwrap();
+
+    assert_eq!(articles.len(), 2);
+    // 每个匹配都是一个分类，重复的分类被去掉
+    assert_eq!(articles[0].categories, vec!["Rust", "Web"]);
+    // 按分隔符拆分
+    assert_eq!(articles[1].categories, vec!["Rust", "Async", "Tokio"]);
+}
+
+#[test]
+fn test_parse_enclosure_and_image() {
+    let html = r#"
+    <html>
+        <body>
+            <div class="episode">
+                <h2 class="title">Episode 1</h2>
+                <a class="link" href="/ep1">Link</a>
+                <img class="cover" data-src="/covers/ep1.jpg" src="/placeholder.gif">
+                <audio><source src="/audio/ep1.mp3" type="audio/mpeg" data-bytes="12345"></audio>
+            </div>
+            <div class="episode">
+                <h2 class="title">Episode 2</h2>
+                <a class="link" href="/ep2">Link</a>
+                <a class="download" href="https://cdn.example.com/ep2.m4a">Download</a>
+            </div>
+            <div class="episode">
+                <h2 class="title">Episode 3</h2>
+                <a class="link" href="/ep3">Link</a>
+            </div>
+        </body>
+    </html>
+    "#;
+
+    let config = ParserConfig {
+        list: ListParserConfig {
+            selector: ".episode".to_string(),
+            link_selector: ".link".into(),
+            title_selector: ".title".into(),
+            enclosure_selector: Some(vec!["audio source".to_string(), "a.download".to_string()].into()),
+            enclosure_length_attr: Some("data-bytes".to_string()),
+            image_selector: Some(FieldSelector {
+                attr: Some("data-src".to_string()),
+                ..FieldSelector::new("img.cover")
+            }),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let articles = HtmlParser::parse(html, &config, "https://example.com", 20).unwrap();
+
+    assert_eq!(articles.len(), 3);
+
+    // 类型和长度取自元素属性，配图取自 data-src
+    assert_eq!(
+        articles[0].enclosure,
+        Some(Enclosure {
+            url: "https://example.com/audio/ep1.mp3".to_string(),
+            mime_type: "audio/mpeg".to_string(),
+            length: Some(12345),
+        })
+    );
+    assert_eq!(articles[0].image, Some("https://example.com/covers/ep1.jpg".to_string()));
+
+    // 回退到 href，类型按扩展名推断
+    assert_eq!(
+        articles[1].enclosure,
+        Some(Enclosure {
+            url: "https://cdn.example.com/ep2.m4a".to_string(),
+            mime_type: "audio/mp4".to_string(),
+            length: None,
+        })
+    );
+    assert!(articles[1].image.is_none());
+
+    assert!(articles[2].enclosure.is_none());
+}
+
+#[test]
+fn test_guid_strategies() {
+    let html = r#"
+    <html>
+        <body>
+            <div class="post">
+                <h2 class="title" data-id="post-42">Article</h2>
+                <a class="link" href="/article?utm_source=rss&b=2&a=1#top">Link</a>
+            </div>
+        </body>
+    </html>
+    "#;
+
+    let parse = |guid: Option<GuidConfig>| {
+        let config = ParserConfig {
+            list: ListParserConfig {
+                selector: ".post".to_string(),
+                link_selector: ".link".into(),
+                title_selector: ".title".into(),
+                ..Default::default()
+            },
+            guid,
+            ..Default::default()
+        };
+        HtmlParser::parse(html, &config, "https://example.com", 20).unwrap().remove(0)
+    };
+
+    // 默认不生成 GUID，由生成器回退到链接
+    assert!(parse(None).guid.is_none());
+
+    let article = parse(Some(GuidConfig::Link {
+        strip_params: vec!["utm_*".to_string()],
+    }));
+    assert_eq!(article.guid, Some("https://example.com/article?a=1&b=2".to_string()));
+    // 链接本身保持不变
+    assert_eq!(article.link, "https://example.com/article?utm_source=rss&b=2&a=1#top");
+
+    let article = parse(Some(GuidConfig::Selector {
+        selector: vec!["[data-id]".to_string()],
+        attr: Some("data-id".to_string()),
+    }));
+    assert_eq!(article.guid, Some("post-42".to_string()));
+
+    let article = parse(Some(GuidConfig::Hash {
+        fields: vec![GuidField::Title],
+    }));
+    assert_eq!(article.guid.map(|guid| guid.len()), Some(64));
+}
+
+#[test]
+fn test_jsonld_mode() {
+    let html = r#"
+    <html>
+        <head>
+            <script type="application/ld+json">
+            {
+                "@context": "https://schema.org",
+                "@type": "ItemList",
+                "itemListElement": [
+                    {"@type": "ListItem", "position": 1, "item": {
+                        "@type": "BlogPosting",
+                        "headline": "First",
+                        "url": "/first",
+                        "datePublished": "2024-01-01",
+                        "author": {"@type": "Person", "name": "Alice"},
+                        "articleBody": "Body <script>alert(1)<\/script>"
+                    }},
+                    {"@type": "ListItem", "position": 2, "url": "/second", "name": "Second"}
+                ]
+            }
+            </script>
+        </head>
+        <body><div id="app"></div></body>
+    </html>
+    "#;
+
+    let config = ParserConfig {
+        mode: ParserMode::Jsonld,
+        ..Default::default()
+    };
+
+    let articles = HtmlParser::parse(html, &config, "https://example.com", 20).unwrap();
+
+    assert_eq!(articles.len(), 2);
+    assert_eq!(articles[0].title, "First");
+    assert_eq!(articles[0].link, "https://example.com/first");
+    assert!(articles[0].pub_date.is_some());
+    assert_eq!(articles[0].author, Some("Alice".to_string()));
+    assert_eq!(articles[0].content, Some("Body".to_string()));
+    assert_eq!(articles[1].title, "Second");
+    assert_eq!(articles[1].link, "https://example.com/second");
+
+    // 数量限制
+    let articles = HtmlParser::parse(html, &config, "https://example.com", 1).unwrap();
+    assert_eq!(articles.len(), 1);
+}
+
+#[test]
+fn test_jsonld_enrichment() {
+    let html = r#"
+    <html>
+        <head>
+            <script type="application/ld+json">
+            [
+                {"@type": "NewsArticle", "headline": "JSON title", "url": "https://example.com/a",
+                 "description": "From JSON-LD", "datePublished": "2024-03-01T08:00:00Z",
+                 "image": "https://example.com/a.jpg"},
+                {"@type": "NewsArticle", "headline": "Other", "url": "https://example.com/other",
+                 "description": "Unrelated"}
+            ]
+            </script>
+        </head>
+        <body>
+            <div class="post">
+                <h2 class="title">CSS title</h2>
+                <a class="link" href="/a#comments">Link</a>
+                <p class="summary">From CSS</p>
+            </div>
+            <div class="post">
+                <h2 class="title">No match</h2>
+                <a class="link" href="/b">Link</a>
+            </div>
+        </body>
+    </html>
+    "#;
+
+    let config = ParserConfig {
+        list: ListParserConfig {
+            selector: ".post".to_string(),
+            link_selector: ".link".into(),
+            title_selector: ".title".into(),
+            description_selector: Some(".summary".into()),
+            ..Default::default()
+        },
+        enrich: vec![Enrichment::Jsonld],
+        ..Default::default()
+    };
+
+    let articles = HtmlParser::parse(html, &config, "https://example.com", 20).unwrap();
+
+    assert_eq!(articles.len(), 2);
+    // CSS 提取到的字段保持不变，空字段由 JSON-LD 补全
+    assert_eq!(articles[0].title, "CSS title");
+    assert_eq!(articles[0].description, Some("From CSS".to_string()));
+    assert!(articles[0].pub_date.is_some());
+    assert_eq!(articles[0].image, Some("https://example.com/a.jpg".to_string()));
+    // 没有匹配的 JSON-LD 条目
+    assert!(articles[1].description.is_none());
+    assert!(articles[1].pub_date.is_none());
+}
+
+fn compile_plugin(yaml: &str) -> CompiledParser {
+    let config: PluginConfig = serde_yaml::from_str(yaml).unwrap();
+    CompiledParser::for_plugin(&config).unwrap()
+}
+
+#[test]
+fn test_embedded_next_data() {
+    let parser = compile_plugin(
+        r#"
+plugin:
+  name: "next"
+  description: "Next.js site"
+
+source:
+  type: "html"
+  url: "https://example.com"
+  embedded:
+    selector: "script#__NEXT_DATA__"
+
+parser:
+  list:
+    selector: "props.pageProps.posts"
+    title_selector: "title"
+    link_selector: ["permalink", "slug"]
+    date_selector: "publishedAt"
+    author_selector: "author.name"
+    category_selector: "tags[*].name"
+
+feed:
+  title: "Next"
+  description: "Next"
+  link: "https://example.com"
+"#,
+    );
+
+    let html = r#"
+    <html>
+        <body>
+            <div id="__next"></div>
+            <script id="__NEXT_DATA__" type="application/json">
+            {"props": {"pageProps": {"posts": [
+                {"title": "First", "slug": "/blog/first", "publishedAt": 1704067200000,
+                 "author": {"name": "Alice"}, "tags": [{"name": "rust"}, {"name": "web"}]},
+                {"title": "Second", "permalink": "https://example.com/blog/second"},
+                {"slug": "/blog/untitled"}
+            ]}}}
+            </script>
+        </body>
+    </html>
+    "#;
+
+    let articles = parser.parse(html, "https://example.com", 20).unwrap();
+
+    // 没有标题的条目被跳过
+    assert_eq!(articles.len(), 2);
+    assert_eq!(articles[0].title, "First");
+    assert_eq!(articles[0].link, "https://example.com/blog/first");
+    assert_eq!(articles[0].pub_date.unwrap().to_rfc3339(), "2024-01-01T00:00:00+00:00");
+    assert_eq!(articles[0].author, Some("Alice".to_string()));
+    assert_eq!(articles[0].categories, vec!["rust", "web"]);
+    assert_eq!(articles[1].link, "https://example.com/blog/second");
+
+    // 页面中找不到数据时返回解析错误
+    let result = parser.parse("<html><body></body></html>", "https://example.com", 20);
+    assert!(matches!(result, Err(RssHubError::ParseError(_))));
+}
+
+#[test]
+fn test_json_source() {
+    let parser = compile_plugin(
+        r#"
+plugin:
+  name: "api"
+  description: "JSON API"
+
+source:
+  type: "json"
+  url: "https://api.example.com/posts"
+
+parser:
+  list:
+    selector: "$.data.items[*]"
+    title_selector: "title"
+    link_selector: "url"
+    description_selector: "summary"
+  guid:
+    type: selector
+    selector: "id"
+
+feed:
+  title: "API"
+  description: "API"
+  link: "https://example.com"
+"#,
+    );
+
+    let json = r#"{"data": {"items": [
+        {"id": 1, "title": "A", "url": "https://example.com/a", "summary": "Summary A"},
+        {"id": 2, "title": "B", "url": "/b"}
+    ]}}"#;
+
+    let articles = parser.parse(json, "https://example.com", 20).unwrap();
+
+    assert_eq!(articles.len(), 2);
+    assert_eq!(articles[0].guid, Some("1".to_string()));
+    assert_eq!(articles[0].description, Some("Summary A".to_string()));
+    assert_eq!(articles[1].link, "https://example.com/b");
+    assert!(articles[1].description.is_none());
+
+    assert!(parser.parse("not json", "https://example.com", 20).is_err());
+}
+
+#[test]
+fn test_microformats_mode() {
+    let html = r#"
+    <html>
+        <body class="h-feed">
+            <article class="h-entry">
+                <h2 class="p-name"><a class="u-url" href="/2024/first">First</a></h2>
+                <time class="dt-published" datetime="2024-02-01">Feb 1</time>
+                <div class="e-content"><p>Hello <script>x()</script>world</p></div>
+            </article>
+            <article class="h-entry">
+                <h2 class="p-name"><a class="u-url" href="/2024/second">Second</a></h2>
+            </article>
+        </body>
+    </html>
+    "#;
+
+    let config = ParserConfig {
+        mode: ParserMode::Microformats,
+        ..Default::default()
+    };
+
+    let articles = HtmlParser::parse(html, &config, "https://blog.example", 20).unwrap();
+
+    assert_eq!(articles.len(), 2);
+    assert_eq!(articles[0].title, "First");
+    assert_eq!(articles[0].link, "https://blog.example/2024/first");
+    assert!(articles[0].pub_date.is_some());
+    assert_eq!(articles[0].content, Some("<p>Hello world</p>".to_string()));
+    assert_eq!(articles[1].title, "Second");
+
+    let articles = HtmlParser::parse(html, &config, "https://blog.example", 1).unwrap();
+    assert_eq!(articles.len(), 1);
+}
+
+#[test]
+fn test_meta_enrichment_from_detail_page() {
+    let config = ParserConfig {
+        enrich: vec![Enrichment::Meta],
+        ..Default::default()
+    };
+    let parser = CompiledParser::compile(&ParserConfig {
+        list: ListParserConfig {
+            selector: "article".to_string(),
+            title_selector: "h2".into(),
+            link_selector: "a".into(),
+            ..Default::default()
+        },
+        ..config
+    })
+    .unwrap();
+    assert!(parser.fetches_detail());
+
+    let mut article = Article {
+        title: "Ownership".to_string(),
+        link: "https://blog.example.com/posts/ownership".to_string(),
+        author: Some("List Author".to_string()),
+        ..Default::default()
+    };
+    parser
+        .parse_detail(include_str!("fixtures/blog_post.html"), "https://blog.example.com/posts/ownership", &mut article)
+        .unwrap();
+
+    // 列表页已有的值优先
+    assert_eq!(article.author.as_deref(), Some("List Author"));
+    assert_eq!(
+        article.description.as_deref(),
+        Some("A gentle introduction to ownership, moves and borrowing.")
+    );
+    assert_eq!(article.pub_date.unwrap().to_rfc3339(), "2024-04-02T09:00:00+00:00");
+    assert_eq!(article.categories, vec!["rust", "memory"]);
+    assert_eq!(article.image.as_deref(), Some("https://blog.example.com/images/ownership-cover.png"));
+    // 没有配置 content 时不提取正文
+    assert!(article.content.is_none());
+}
+
+#[test]
+fn test_date_from_url_fallback() {
+    let html = r#"
+        <ul>
+            <li><a href="/2024/05/12/first">First</a><time>2024-06-01</time></li>
+            <li><a href="/2024/05/10/second">Second</a></li>
+            <li><a href="/about">About</a></li>
+        </ul>
+    "#;
+
+    let config: ParserConfig = serde_yaml::from_str(
+        r#"
+        list:
+          selector: "li"
+          title_selector: "a"
+          link_selector: "a"
+          date_selector: "time"
+        date_fallback:
+          url_pattern: '/(?P<year>\d{4})/(?P<month>\d{2})/(?P<day>\d{2})/'
+        "#,
+    )
+    .unwrap();
+
+    let articles = HtmlParser::parse(html, &config, "https://blog.example", 20).unwrap();
+
+    // 选择器提取到的日期优先
+    assert_eq!(articles[0].pub_date.unwrap().to_rfc3339(), "2024-06-01T00:00:00+00:00");
+    assert_eq!(articles[1].pub_date.unwrap().to_rfc3339(), "2024-05-10T00:00:00+00:00");
+    assert!(articles[2].pub_date.is_none());
+}
+
+#[test]
+fn test_list_date_wins_over_meta_with_url_fallback() {
+    let html = r#"
+        <ul>
+            <li><a href="/2024/04/02/ownership">Ownership</a><time>2024-04-02</time></li>
+            <li><a href="/2024/04/01/borrowing">Borrowing</a></li>
+        </ul>
+    "#;
+
+    let config: ParserConfig = serde_yaml::from_str(
+        r#"
+        list:
+          selector: "li"
+          title_selector: "a"
+          link_selector: "a"
+          date_selector: "time"
+        enrich: [meta]
+        date_fallback:
+          url_pattern: '/(?P<year>\d{4})/(?P<month>\d{2})/(?P<day>\d{2})/'
+        "#,
+    )
+    .unwrap();
+    let parser = CompiledParser::compile(&config).unwrap();
+
+    let mut articles = parser.parse(html, "https://blog.example.com", 20).unwrap();
+    // 链接中的日期等详情页解析之后再使用
+    assert!(articles[1].pub_date.is_none());
+
+    for article in &mut articles {
+        let link = article.link.clone();
+        parser
+            .parse_detail(include_str!("fixtures/blog_post.html"), &link, article)
+            .unwrap();
+    }
+    parser.fill_url_dates(&mut articles);
+
+    // 列表页的日期与链接中的日期相同，仍然优先于详情页
+    assert_eq!(articles[0].pub_date.unwrap().to_rfc3339(), "2024-04-02T00:00:00+00:00");
+    // 列表页没有日期时使用详情页的日期，而不是链接中的日期
+    assert_eq!(articles[1].pub_date.unwrap().to_rfc3339(), "2024-04-02T09:00:00+00:00");
+}
+
+#[test]
+fn test_extra_fields() {
+    let html = r#"
+        <ul>
+            <li><a href="/item/1">Show HN</a><span class="score">42 points</span><a class="comments">7</a></li>
+            <li><a href="/item/2">Ask HN</a></li>
+        </ul>
+    "#;
+
+    let config: ParserConfig = serde_yaml::from_str(
+        r#"
+        list:
+          selector: "li"
+          title_selector: "a[href]"
+          link_selector: "a[href]"
+          extra_fields:
+            score:
+              selector: "span.score"
+              transforms:
+                - type: regex
+                  pattern: '(\d+)'
+            comments: "a.comments"
+        "#,
+    )
+    .unwrap();
+
+    let articles = HtmlParser::parse(html, &config, "https://news.example", 20).unwrap();
+
+    assert_eq!(articles[0].extra.get("score").map(String::as_str), Some("42"));
+    assert_eq!(articles[0].extra.get("comments").map(String::as_str), Some("7"));
+    // 没有匹配到的自定义字段不出现在 extra 中
+    assert!(articles[1].extra.is_empty());
+}
+
+#[test]
+fn test_invalid_extra_field_name() {
+    let config: ParserConfig = serde_yaml::from_str(
+        r#"
+        list:
+          selector: "li"
+          title_selector: "a"
+          link_selector: "a"
+          extra_fields:
+            "score points": "span"
+        "#,
+    )
+    .unwrap();
+
+    assert!(CompiledParser::compile(&config).is_err());
+}
+
+#[test]
+fn test_multiple_lists() {
+    let html = r#"
+        <section class="featured">
+            <article><h1><a href="/post/1">Featured Post</a></h1><p class="lede">Big news</p></article>
+        </section>
+        <ul class="latest">
+            <li><a href="/post/2">Latest One</a><time>2024-05-02</time></li>
+            <li><a href="/post/3">Latest Two</a><time>2024-05-01</time></li>
+        </ul>
+    "#;
+
+    let config: ParserConfig = serde_yaml::from_str(
+        r#"
+        lists:
+          - selector: "section.featured article"
+            title_selector: "h1 a"
+            link_selector: "h1 a"
+            description_selector: "p.lede"
+          - selector: "ul.latest li"
+            title_selector: "a"
+            link_selector: "a"
+            date_selector: "time"
+            date_format: "%Y-%m-%d"
+        "#,
+    )
+    .unwrap();
+
+    // 各规则的结果按顺序拼接
+    let articles = HtmlParser::parse(html, &config, "https://example.com", 20).unwrap();
+    let titles: Vec<_> = articles.iter().map(|a| a.title.as_str()).collect();
+    assert_eq!(titles, vec!["Featured Post", "Latest One", "Latest Two"]);
+    assert_eq!(articles[0].description.as_deref(), Some("Big news"));
+    assert!(articles[1].pub_date.is_some());
+
+    // 数量限制作用于拼接后的结果
+    let articles = HtmlParser::parse(html, &config, "https://example.com", 2).unwrap();
+    assert_eq!(articles.len(), 2);
+    assert_eq!(articles[1].title, "Latest One");
+}
+
+#[test]
+fn test_multiple_lists_errors() {
+    let config: ParserConfig = serde_yaml::from_str(
+        r#"
+        lists:
+          - selector: "li"
+            title_selector: "a"
+            link_selector: "a"
+          - selector: "div["
+            title_selector: "a"
+            link_selector: "a"
+        "#,
+    )
+    .unwrap();
+
+    let error = CompiledParser::compile(&config).unwrap_err().to_string();
+    assert!(error.contains("parser.lists[1]"), "{}", error);
+
+    // list 和 lists 不能同时使用
+    let config: ParserConfig = serde_yaml::from_str(
+        r#"
+        list:
+          selector: "li"
+          title_selector: "a"
+          link_selector: "a"
+        lists:
+          - selector: "li"
+            title_selector: "a"
+            link_selector: "a"
+        "#,
+    )
+    .unwrap();
+
+    assert!(CompiledParser::compile(&config).is_err());
 }
@@ -145,6 +145,96 @@ feed:
     let _ = fs::remove_file(&test_config_path);
 }
 
+#[test]
+fn test_validate_config_with_invalid_transform_regex() {
+    let configs_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("configs");
+    let parser = ConfigParser::new(configs_dir.to_string_lossy().to_string());
+
+    let yaml = r#"
+plugin:
+  name: "invalid-regex"
+  description: "Test"
+  version: "1.0.0"
+
+source:
+  type: "html"
+  url: "https://example.com"
+
+parser:
+  list:
+    selector: "div.post"
+    link_selector: "a"
+    title_selector: "a"
+    author_selector:
+      selector: "span.author"
+      transforms:
+        - type: regex
+          pattern: "Posted by (.*"
+
+feed:
+  title: "Test Feed"
+  description: "Test"
+  link: "https://example.com"
+"#;
+
+    let test_config_path = configs_dir.join("invalid-regex.yml");
+    fs::write(&test_config_path, yaml).unwrap();
+
+    // 无效的正则应该在加载配置时被拒绝
+    let result = parser.load_plugin("invalid-regex");
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert!(e.to_string().contains("author_selector"));
+        assert!(e.to_string().contains("Invalid regex"));
+    }
+
+    // 清理
+    let _ = fs::remove_file(&test_config_path);
+}
+
+#[test]
+fn test_validate_config_item_selector_unsupported_for_json() {
+    let configs_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("configs");
+    let parser = ConfigParser::new(configs_dir.to_string_lossy().to_string());
+
+    let yaml = r#"
+plugin:
+  name: "json-item-selector"
+  description: "Test"
+  version: "1.0.0"
+
+source:
+  type: "json"
+  url: "https://example.com/api"
+
+parser:
+  list:
+    selector: "div.post"
+    item_selector: "div.item"
+    link_selector: "a"
+    title_selector: "a"
+
+feed:
+  title: "Test Feed"
+  description: "Test"
+  link: "https://example.com"
+"#;
+
+    let test_config_path = configs_dir.join("json-item-selector.yml");
+    fs::write(&test_config_path, yaml).unwrap();
+
+    let result = parser.load_plugin("json-item-selector");
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert!(e.to_string().contains("item_selector"));
+    }
+
+    // 清理
+    let _ = fs::remove_file(&test_config_path);
+}
+
 #[test]
 fn test_validate_config_with_missing_file() {
     let configs_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("configs");
@@ -228,3 +318,243 @@ fn test_get_plugin_mtime() {
     // 验证时间是一个合理的时间戳（在过去）
     assert!(time.duration_since(std::time::UNIX_EPOCH).is_ok());
 }
+
+#[test]
+fn test_validate_config_jsonld_mode_without_list() {
+    let configs_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("configs");
+    let parser = ConfigParser::new(configs_dir.to_string_lossy().to_string());
+
+    let yaml = r#"
+plugin:
+  name: "jsonld-mode"
+  description: "Test"
+
+source:
+  type: "html"
+  url: "https://example.com"
+
+parser:
+  mode: jsonld
+
+feed:
+  title: "Test Feed"
+  description: "Test"
+  link: "https://example.com"
+"#;
+
+    let test_config_path = configs_dir.join("jsonld-mode.yml");
+    fs::write(&test_config_path, yaml).unwrap();
+
+    // jsonld 模式不需要 list 选择器
+    let result = parser.load_plugin("jsonld-mode");
+
+    // 清理
+    let _ = fs::remove_file(&test_config_path);
+
+    assert!(result.is_ok(), "{:?}", result.err());
+}
+
+#[test]
+fn test_validate_config_with_invalid_filter_regex() {
+    let configs_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("configs");
+    let parser = ConfigParser::new(configs_dir.to_string_lossy().to_string());
+
+    let yaml = r#"
+plugin:
+  name: "invalid-filter"
+  description: "Test"
+
+source:
+  type: "html"
+  url: "https://example.com"
+
+parser:
+  list:
+    selector: "div.post"
+    link_selector: "a"
+    title_selector: "a"
+
+feed:
+  title: "Test Feed"
+  description: "Test"
+  link: "https://example.com"
+
+filter:
+  exclude:
+    - field: title
+      regex: "[unclosed"
+"#;
+
+    let test_config_path = configs_dir.join("invalid-filter.yml");
+    fs::write(&test_config_path, yaml).unwrap();
+
+    let result = parser.load_plugin("invalid-filter");
+
+    // 清理
+    let _ = fs::remove_file(&test_config_path);
+
+    assert!(result.is_err());
+    assert!(result.unwrap_err().to_string().contains("filter.exclude"));
+}
+
+#[test]
+fn test_validate_monitor_mode_requires_monitor_config() {
+    let configs_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("configs");
+    let parser = ConfigParser::new(configs_dir.to_string_lossy().to_string());
+
+    let yaml = r#"
+plugin:
+  name: "monitor-missing"
+  description: "Test"
+
+source:
+  type: "html"
+  url: "https://example.com/pricing"
+
+parser:
+  mode: monitor
+
+feed:
+  title: "Pricing"
+  description: "Test"
+  link: "https://example.com/pricing"
+"#;
+
+    let test_config_path = configs_dir.join("monitor-missing.yml");
+    fs::write(&test_config_path, yaml).unwrap();
+
+    let result = parser.load_plugin("monitor-missing");
+
+    // 清理
+    let _ = fs::remove_file(&test_config_path);
+
+    assert!(result.is_err());
+    assert!(result.unwrap_err().to_string().contains("parser.monitor"));
+}
+
+#[test]
+fn test_validate_config_with_unknown_template_placeholder() {
+    let configs_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("configs");
+    let parser = ConfigParser::new(configs_dir.to_string_lossy().to_string());
+
+    let yaml = r#"
+plugin:
+  name: "invalid-template"
+  description: "Test"
+
+source:
+  type: "html"
+  url: "https://example.com"
+
+parser:
+  list:
+    selector: "div.post"
+    link_selector: "a"
+    title_selector: "a"
+
+feed:
+  title: "Test Feed"
+  description: "Test"
+  link: "https://example.com"
+  title_template: "[{categroy}] {title}"
+"#;
+
+    let test_config_path = configs_dir.join("invalid-template.yml");
+    fs::write(&test_config_path, yaml).unwrap();
+
+    let result = parser.load_plugin("invalid-template");
+
+    // 清理
+    let _ = fs::remove_file(&test_config_path);
+
+    assert!(result.is_err());
+    let message = result.unwrap_err().to_string();
+    assert!(message.contains("feed.title_template"));
+    assert!(message.contains("{categroy}"));
+}
+
+#[test]
+fn test_validate_config_with_multiple_lists() {
+    let configs_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("configs");
+    let parser = ConfigParser::new(configs_dir.to_string_lossy().to_string());
+
+    let yaml = r#"
+plugin:
+  name: "multiple-lists"
+  description: "Test"
+
+source:
+  type: "html"
+  url: "https://example.com"
+
+parser:
+  lists:
+    - selector: "section.featured article"
+      title_selector: "h1 a"
+      link_selector: "h1 a"
+    - selector: "ul.latest li"
+      title_selector: "a"
+      link_selector: "a"
+      extra_fields:
+        score: "span.score"
+
+feed:
+  title: "Test Feed"
+  description: "Test"
+  link: "https://example.com"
+  title_template: "{title} {extra.score}"
+"#;
+
+    let test_config_path = configs_dir.join("multiple-lists.yml");
+    fs::write(&test_config_path, yaml).unwrap();
+
+    // 模板可以引用任一规则声明的自定义字段
+    let result = parser.load_plugin("multiple-lists");
+
+    // 清理
+    let _ = fs::remove_file(&test_config_path);
+
+    let config = result.unwrap();
+    assert_eq!(config.parser.list_blocks().len(), 2);
+}
+
+#[test]
+fn test_validate_config_list_rejected_outside_css_mode() {
+    let configs_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("configs");
+    let parser = ConfigParser::new(configs_dir.to_string_lossy().to_string());
+
+    let yaml = r#"
+plugin:
+  name: "microformats-with-list"
+  description: "Test"
+
+source:
+  type: "html"
+  url: "https://example.com"
+
+parser:
+  mode: microformats
+  list:
+    selector: "section.posts"
+    item_selector: "article"
+    title_selector: "h2 a"
+    link_selector: "h2 a"
+
+feed:
+  title: "Test Feed"
+  description: "Test"
+  link: "https://example.com"
+"#;
+
+    let test_config_path = configs_dir.join("microformats-with-list.yml");
+    fs::write(&test_config_path, yaml).unwrap();
+
+    // 非 CSS 模式会忽略 list，应在加载时报错
+    let result = parser.load_plugin("microformats-with-list");
+
+    // 清理
+    let _ = fs::remove_file(&test_config_path);
+
+    assert!(result.is_err());
+    assert!(result.unwrap_err().to_string().contains("css mode"));
+}
@@ -0,0 +1,125 @@
+use rust_rsshub::parser::readability::Readability;
+use rust_rsshub::parser::CompiledParser;
+use rust_rsshub::{Article, ParserConfig};
+use scraper::Html;
+
+const BLOG_POST: &str = include_str!("fixtures/blog_post.html");
+const NEWS_ARTICLE: &str = include_str!("fixtures/news_article.html");
+
+fn extract(html: &str, base_url: &str) -> String {
+    Readability::new()
+        .extract(&Html::parse_document(html), base_url)
+        .expect("main content should be found")
+}
+
+#[test]
+fn test_readability_blog_post() {
+    let content = extract(BLOG_POST, "https://blog.example.com/posts/ownership");
+
+    assert!(content.contains("Every value in Rust has a single owner"));
+    assert!(content.contains("takes_ownership(s);"));
+    // 正文中的链接和图片保留并转为绝对地址
+    assert!(content.contains(r#"href="https://blog.example.com/posts/borrowing""#));
+    assert!(content.contains(r#"src="https://blog.example.com/images/ownership.png""#));
+
+    // 导航、分享、推荐、评论、侧边栏、页脚和脚本都被移除
+    assert!(!content.contains("Archive"));
+    assert!(!content.contains("Tweet"));
+    assert!(!content.contains("Lifetimes explained"));
+    assert!(!content.contains("Great post"));
+    assert!(!content.contains("About the author"));
+    assert!(!content.contains("Copyright"));
+    assert!(!content.contains("analytics"));
+}
+
+#[test]
+fn test_readability_news_article() {
+    let content = extract(NEWS_ARTICLE, "https://news.example.com/2024/06/01/metro.html");
+
+    assert!(content.contains("城市地铁新线路正式开通运营"));
+    assert!(content.contains("早六时至晚十一时"));
+
+    assert!(!content.contains("热门排行"));
+    assert!(!content.contains("高考首日"));
+    assert!(!content.contains("广告服务"));
+    assert!(!content.contains("banner.jpg"));
+    assert!(!content.contains("trackPageView"));
+}
+
+#[test]
+fn test_content_auto_mode() {
+    let config: ParserConfig = serde_yaml::from_str(
+        r#"
+        list:
+          selector: "article"
+          title_selector: "h2"
+          link_selector: "a"
+        content:
+          mode: auto
+          cleanup_selectors: ["pre"]
+        "#,
+    )
+    .unwrap();
+    let parser = CompiledParser::compile(&config).unwrap();
+
+    let mut article = Article {
+        title: "Understanding Ownership in Rust".to_string(),
+        link: "https://blog.example.com/posts/ownership".to_string(),
+        content: Some("<p>Excerpt</p>".to_string()),
+        ..Default::default()
+    };
+    parser.parse_detail(BLOG_POST, &article.link.clone(), &mut article).unwrap();
+
+    let content = article.content.unwrap();
+    assert!(content.contains("Every value in Rust has a single owner"));
+    // 自定义清理选择器同样生效
+    assert!(!content.contains("takes_ownership"));
+}
+
+#[test]
+fn test_content_selector_falls_back_to_auto() {
+    let config: ParserConfig = serde_yaml::from_str(
+        r#"
+        list:
+          selector: "article"
+          title_selector: "h2"
+          link_selector: "a"
+        content:
+          selector: "div.left"
+          content_selector: "div.does-not-exist"
+        "#,
+    )
+    .unwrap();
+    let parser = CompiledParser::compile(&config).unwrap();
+
+    let mut article = Article::default();
+    parser
+        .parse_detail(NEWS_ARTICLE, "https://news.example.com/2024/06/01/metro.html", &mut article)
+        .unwrap();
+
+    assert!(article.content.unwrap().contains("日均客流将超过四十万人次"));
+}
+
+#[test]
+fn test_content_selector_match() {
+    let config: ParserConfig = serde_yaml::from_str(
+        r#"
+        list:
+          selector: "article"
+          title_selector: "h2"
+          link_selector: "a"
+        content:
+          selector: "div.left"
+          content_selector: "div.info"
+        "#,
+    )
+    .unwrap();
+    let parser = CompiledParser::compile(&config).unwrap();
+
+    let mut article = Article::default();
+    parser
+        .parse_detail(NEWS_ARTICLE, "https://news.example.com/", &mut article)
+        .unwrap();
+
+    assert_eq!(article.content.as_deref(), Some("2024-06-01 09:30 来源：示例新闻网"));
+}